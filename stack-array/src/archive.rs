@@ -0,0 +1,178 @@
+use crate::*;
+use rkyv::{
+    bytecheck::CheckBytes,
+    munge::munge,
+    primitive::ArchivedUsize,
+    rancor::{fail, Fallible, Source},
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+/// An archived [`ArrayBuf`].
+///
+/// It is fixed-size like `ArrayBuf`: A length followed by `N` slots, Only the first `len` slots are initialized.
+/// A `HeapArrayBuf` (With the `std` feature) is archived the same way, The slots are stored inline rather than boxed.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+///
+/// let arr: ArrayBuf<u32, 4> = ArrayBuf::from([1, 2].as_slice());
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&arr).unwrap();
+///
+/// let archived = rkyv::access::<ArchivedArrayBuf<rkyv::Archived<u32>, 4>, rkyv::rancor::Error>(&bytes).unwrap();
+/// assert_eq!(archived.len(), 2);
+/// assert_eq!(archived[1], 2);
+/// ```
+#[repr(C)]
+pub struct ArchivedArrayBuf<T, const N: usize> {
+    len: ArchivedUsize,
+    buf: [MaybeUninit<T>; N],
+}
+
+// SAFETY: `ArchivedArrayBuf` is `repr(C)`, And `len` and `buf` are portable as long as `T` is portable.
+unsafe impl<T: Portable, const N: usize> Portable for ArchivedArrayBuf<T, N> {}
+
+impl<T, const N: usize> ArchivedArrayBuf<T, N> {
+    /// Returns the number of elements in the archived array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.to_native() as usize
+    }
+
+    /// Returns `true` if the archived array contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements the archived array can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Extracts a slice containing the entire archived array.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: An archived array has `len <= N` and only initialized slots within `len`,
+        // that is what `CheckBytes` validate.
+        unsafe { slice::from_raw_parts(self.buf.as_ptr() as *const T, self.len()) }
+    }
+}
+
+impl<T, const N: usize> Deref for ArchivedArrayBuf<T, N> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArchivedArrayBuf<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+macro_rules! impl_archive {
+    ($($ty:ident),*) => {$(
+        impl<T: PartialEq<U>, U, const N: usize> PartialEq<$ty<U, N>> for ArchivedArrayBuf<T, N> {
+            #[inline]
+            fn eq(&self, other: &$ty<U, N>) -> bool {
+                self[..] == other[..]
+            }
+        }
+
+        impl<T: Archive, const N: usize> Archive for $ty<T, N> {
+            type Archived = ArchivedArrayBuf<T::Archived, N>;
+            type Resolver = $ty<T::Resolver, N>;
+
+            fn resolve(&self, mut resolver: Self::Resolver, out: Place<Self::Archived>) {
+                munge!(let ArchivedArrayBuf { len, buf } = out);
+                self.len.resolve((), len);
+                // SAFETY: `[MaybeUninit<T>; N]` has the same layout as `[T; N]`,
+                // And all of the bytes are initialized (zeroed) by the serializer.
+                let buf = unsafe { buf.cast_unchecked::<[T::Archived; N]>() };
+                for (i, (value, resolver)) in self.iter().zip(resolver.drain(..)).enumerate() {
+                    // SAFETY: `i < self.len <= N`
+                    value.resolve(resolver, unsafe { buf.index(i) });
+                }
+            }
+        }
+
+        impl<T, S, const N: usize> Serialize<S> for $ty<T, N>
+        where
+            T: Serialize<S>,
+            S: Fallible + ?Sized,
+        {
+            fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+                let mut resolvers = $ty::new();
+                for value in self.iter() {
+                    resolvers.push(value.serialize(serializer)?);
+                }
+                Ok(resolvers)
+            }
+        }
+
+        impl<T, D, const N: usize> Deserialize<$ty<T, N>, D> for ArchivedArrayBuf<T::Archived, N>
+        where
+            T: Archive,
+            T::Archived: Deserialize<T, D>,
+            D: Fallible + ?Sized,
+        {
+            fn deserialize(&self, deserializer: &mut D) -> Result<$ty<T, N>, D::Error> {
+                let mut arr = $ty::new();
+                for value in self.iter() {
+                    arr.push(value.deserialize(deserializer)?);
+                }
+                Ok(arr)
+            }
+        }
+    )*};
+}
+
+impl_archive!(ArrayBuf);
+#[cfg(feature = "std")]
+impl_archive!(HeapArrayBuf);
+
+/// An error resulting from an archived array, Whose length is greater than its capacity.
+#[derive(Debug)]
+pub struct ArchivedLengthError {
+    len: usize,
+    capacity: usize,
+}
+
+impl fmt::Display for ArchivedLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "archived length {} is greater than capacity {}",
+            self.len, self.capacity
+        )
+    }
+}
+
+impl core::error::Error for ArchivedLengthError {}
+
+unsafe impl<T, C, const N: usize> CheckBytes<C> for ArchivedArrayBuf<T, N>
+where
+    T: CheckBytes<C>,
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        let len = ptr::addr_of!((*value).len);
+        ArchivedUsize::check_bytes(len, context)?;
+
+        let len = (*len).to_native() as usize;
+        if len > N {
+            fail!(ArchivedLengthError { len, capacity: N });
+        }
+        let buf = ptr::addr_of!((*value).buf) as *const T;
+        for i in 0..len {
+            T::check_bytes(buf.add(i), context)?;
+        }
+        Ok(())
+    }
+}
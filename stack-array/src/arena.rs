@@ -0,0 +1,181 @@
+use crate::*;
+use allocator_api2::alloc::{AllocError, Allocator, Layout};
+use core::cell::{Cell, UnsafeCell};
+
+/// A bump allocator, That hand out memory from a fixed number of bytes.
+///
+/// [`Allocator`] is implemented for `&StackArena`, So the arena must outlive every collection allocated from it.
+/// Deallocation only reclaim memory, If the block is the last allocation, Otherwise memory is reclaimed by [`reset`].
+///
+/// [`reset`]: StackArena::reset
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+/// use allocator_api2::vec::Vec;
+///
+/// let arena: StackArena<64> = StackArena::new();
+/// let mut list = Vec::new_in(&arena);
+/// list.extend_from_slice(&[1_u32, 2, 3]);
+///
+/// assert_eq!(list, [1, 2, 3]);
+/// assert!(arena.used() >= 12);
+/// ```
+pub struct StackArena<const BYTES: usize> {
+    offset: Cell<usize>,
+    buf: UnsafeCell<[MaybeUninit<u8>; BYTES]>,
+}
+
+impl<const BYTES: usize> StackArena<BYTES> {
+    /// Constructs a new, empty `StackArena`
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            offset: Cell::new(0),
+            buf: UnsafeCell::new([MaybeUninit::uninit(); BYTES]),
+        }
+    }
+
+    /// Returns the total number of bytes the arena can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        BYTES
+    }
+
+    /// Returns the number of bytes currently in use, Including alignment padding.
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.offset.get()
+    }
+
+    /// Returns the number of bytes that are still available.
+    ///
+    /// An allocation may fail even if its size is less than this, Because of alignment padding.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        BYTES - self.offset.get()
+    }
+
+    /// Free all allocations at once.
+    ///
+    /// This take `&mut self`, So no collection can still borrow the arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    /// use allocator_api2::boxed::Box;
+    ///
+    /// let mut arena: StackArena<8> = StackArena::new();
+    /// let a = Box::new_in([1_u8; 4], &arena);
+    /// let b = Box::new_in([2_u8; 4], &arena);
+    /// drop(a);
+    /// drop(b);
+    /// // `a` is not the last allocation, So its memory is not reclaimed.
+    /// assert!(Box::try_new_in([3_u8; 8], &arena).is_err());
+    ///
+    /// arena.reset();
+    /// assert!(Box::try_new_in([3_u8; 8], &arena).is_ok());
+    /// ```
+    #[inline]
+    pub fn reset(&mut self) {
+        self.offset.set(0);
+    }
+
+    #[inline]
+    fn base(&self) -> *mut u8 {
+        self.buf.get() as *mut u8
+    }
+
+    #[inline]
+    fn offset_of(&self, ptr: NonNull<u8>) -> usize {
+        ptr.as_ptr() as usize - self.base() as usize
+    }
+
+    /// Returns `true`, If the block is at the end of used memory.
+    #[inline]
+    fn is_last(&self, ptr: NonNull<u8>, layout: Layout) -> bool {
+        self.offset_of(ptr) + layout.size() == self.offset.get()
+    }
+
+    fn alloc_at(&self, start: usize, size: usize) -> Result<NonNull<[u8]>, AllocError> {
+        let end = start.checked_add(size).ok_or(AllocError)?;
+        if end > BYTES {
+            return Err(AllocError);
+        }
+        self.offset.set(end);
+        // SAFETY: `start <= end <= BYTES`, So the pointer is in bounds of `buf`.
+        let ptr = unsafe { NonNull::new_unchecked(self.base().add(start)) };
+        Ok(NonNull::slice_from_raw_parts(ptr, size))
+    }
+}
+
+impl<const BYTES: usize> Default for StackArena<BYTES> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BYTES: usize> fmt::Debug for StackArena<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StackArena")
+            .field("used", &self.used())
+            .field("capacity", &BYTES)
+            .finish()
+    }
+}
+
+unsafe impl<const BYTES: usize> Allocator for &StackArena<BYTES> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        // Align the real address, Not the offset. Because `buf` is only aligned to `1`.
+        let base = self.base() as usize;
+        let addr = base.checked_add(self.offset.get()).ok_or(AllocError)?;
+        let aligned =
+            addr.checked_add(layout.align() - 1).ok_or(AllocError)? & !(layout.align() - 1);
+        self.alloc_at(aligned - base, layout.size())
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if self.is_last(ptr, layout) {
+            self.offset.set(self.offset_of(ptr));
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() >= old_layout.size());
+
+        let aligned = (ptr.as_ptr() as usize).is_multiple_of(new_layout.align());
+        if aligned && self.is_last(ptr, old_layout) {
+            return self.alloc_at(self.offset_of(ptr), new_layout.size());
+        }
+        let new_ptr = self.allocate(new_layout)?;
+        ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr() as *mut u8, old_layout.size());
+        Ok(new_ptr)
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() <= old_layout.size());
+
+        if !(ptr.as_ptr() as usize).is_multiple_of(new_layout.align()) {
+            let new_ptr = self.allocate(new_layout)?;
+            ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr() as *mut u8, new_layout.size());
+            return Ok(new_ptr);
+        }
+        if self.is_last(ptr, old_layout) {
+            return self.alloc_at(self.offset_of(ptr), new_layout.size());
+        }
+        Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()))
+    }
+}
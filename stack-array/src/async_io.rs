@@ -0,0 +1,93 @@
+//! Async I/O traits for `ArrayBuf<u8, N>` and `HeapArrayBuf<u8, N>`,
+//! With the same bounded semantics as [`std::io::Write`].
+//!
+//! Writes never wait: A write larger than the remaining capacity is a short write,
+//! And `write_all` fails with `WriteZero` once the array is full.
+
+use crate::{
+    byte_io::{read_front, write_within_capacity},
+    *,
+};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::io;
+
+macro_rules! impl_async_io {
+    ($($ty:ident),*) => {$(
+        #[cfg(feature = "tokio")]
+        impl<const N: usize> tokio::io::AsyncWrite for $ty<u8, N> {
+            #[inline]
+            fn poll_write(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+                buf: &[u8],
+            ) -> Poll<io::Result<usize>> {
+                Poll::Ready(Ok(write_within_capacity(self.get_mut(), buf)))
+            }
+
+            #[inline]
+            fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+                Poll::Ready(Ok(()))
+            }
+
+            #[inline]
+            fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+                Poll::Ready(Ok(()))
+            }
+        }
+
+        /// Consumes bytes from the front of the array, Wrap it in an `io::Cursor` to read without consuming.
+        #[cfg(feature = "tokio")]
+        impl<const N: usize> tokio::io::AsyncRead for $ty<u8, N> {
+            #[inline]
+            fn poll_read(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+                buf: &mut tokio::io::ReadBuf<'_>,
+            ) -> Poll<io::Result<()>> {
+                let amt = read_front(self.get_mut(), buf.initialize_unfilled());
+                buf.advance(amt);
+                Poll::Ready(Ok(()))
+            }
+        }
+
+        #[cfg(feature = "futures-io")]
+        impl<const N: usize> futures_io::AsyncWrite for $ty<u8, N> {
+            #[inline]
+            fn poll_write(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+                buf: &[u8],
+            ) -> Poll<io::Result<usize>> {
+                Poll::Ready(Ok(write_within_capacity(self.get_mut(), buf)))
+            }
+
+            #[inline]
+            fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+                Poll::Ready(Ok(()))
+            }
+
+            #[inline]
+            fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+                Poll::Ready(Ok(()))
+            }
+        }
+
+        /// Consumes bytes from the front of the array.
+        #[cfg(feature = "futures-io")]
+        impl<const N: usize> futures_io::AsyncRead for $ty<u8, N> {
+            #[inline]
+            fn poll_read(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+                buf: &mut [u8],
+            ) -> Poll<io::Result<usize>> {
+                Poll::Ready(Ok(read_front(self.get_mut(), buf)))
+            }
+        }
+    )*};
+}
+
+impl_async_io!(ArrayBuf, HeapArrayBuf);
@@ -0,0 +1,358 @@
+use crate::*;
+use std::{
+    error,
+    io::{self, BufRead, Read, Seek, SeekFrom, Write},
+};
+
+/// Like [`io::BufReader`], But the buffer is an inline [`ArrayBuf<u8, N>`].
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+/// use std::io::BufRead;
+///
+/// let mut reader: StackBufReader<_, 16> = StackBufReader::new(b"Hello\nWorld".as_slice());
+/// let mut line = String::new();
+/// reader.read_line(&mut line).unwrap();
+///
+/// assert_eq!(line, "Hello\n");
+/// assert_eq!(reader.buffer(), b"World");
+/// ```
+pub struct StackBufReader<R, const N: usize> {
+    inner: R,
+    buf: ArrayBuf<u8, N>,
+    /// Number of bytes of `buf` already consumed.
+    pos: usize,
+    /// Number of bytes of `buf` that have been initialized, Even if they are not filled.
+    init: usize,
+}
+
+impl<R, const N: usize> StackBufReader<R, N> {
+    /// Creates a new `StackBufReader` with a buffer of `N` bytes.
+    #[inline]
+    pub const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buf: ArrayBuf::new(),
+            pos: 0,
+            init: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns a reference to the internally buffered data.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..]
+    }
+
+    /// Returns the number of bytes the internal buffer can hold at once.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Unwraps this `StackBufReader`, Returning the underlying reader.
+    ///
+    /// Note that any leftover data in the internal buffer is lost.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    #[inline]
+    fn discard_buffer(&mut self) {
+        self.buf.clear();
+        self.pos = 0;
+    }
+}
+
+impl<R: Read, const N: usize> Read for StackBufReader<R, N> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        // If we don't have any buffered data and we're doing a massive read
+        // (larger than our internal buffer), bypass our internal buffer entirely.
+        if self.pos == self.buf.len() && out.len() >= N {
+            self.discard_buffer();
+            return self.inner.read(out);
+        }
+        let amt = self.fill_buf()?.read(out)?;
+        self.consume(amt);
+        Ok(amt)
+    }
+}
+
+impl<R: Read, const N: usize> BufRead for StackBufReader<R, N> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos >= self.buf.len() {
+            self.discard_buffer();
+            let ptr = self.buf.as_mut_ptr();
+            unsafe {
+                if self.init < N {
+                    ptr::write_bytes(ptr.add(self.init), 0, N - self.init);
+                    self.init = N;
+                }
+                // SAFETY: All `N` bytes are initialized.
+                let amt = self.inner.read(slice::from_raw_parts_mut(ptr, N))?;
+                self.buf.set_len(cmp::min(amt, N));
+            }
+        }
+        Ok(self.buffer())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos = cmp::min(self.pos + amt, self.buf.len());
+    }
+}
+
+impl<R: Seek, const N: usize> Seek for StackBufReader<R, N> {
+    /// Seek to an offset, In bytes, In the underlying reader.
+    ///
+    /// Seeking always discards the internal buffer, Even if the seek position would otherwise fall within it.
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let result = if let SeekFrom::Current(n) = pos {
+            let remainder = (self.buf.len() - self.pos) as i64;
+            // The underlying reader is ahead of us by `remainder` bytes.
+            match n.checked_sub(remainder) {
+                Some(offset) => self.inner.seek(SeekFrom::Current(offset))?,
+                None => {
+                    self.inner.seek(SeekFrom::Current(-remainder))?;
+                    self.discard_buffer();
+                    self.inner.seek(SeekFrom::Current(n))?
+                }
+            }
+        } else {
+            self.inner.seek(pos)?
+        };
+        self.discard_buffer();
+        Ok(result)
+    }
+}
+
+impl<R: fmt::Debug, const N: usize> fmt::Debug for StackBufReader<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StackBufReader")
+            .field("reader", &self.inner)
+            .field(
+                "buffer",
+                &format_args!("{}/{}", self.buf.len() - self.pos, N),
+            )
+            .finish()
+    }
+}
+
+/// Like [`io::BufWriter`], But the buffer is an inline [`ArrayBuf<u8, N>`].
+///
+/// The buffer is flushed when the writer is dropped, Errors on drop are ignored.
+/// Call [`flush`](Write::flush) or [`into_inner`](StackBufWriter::into_inner) to handle them.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+/// use std::io::Write;
+///
+/// let mut writer: StackBufWriter<_, 16> = StackBufWriter::new(Vec::new());
+/// writer.write_all(b"Hello").unwrap();
+/// assert!(writer.get_ref().is_empty());
+///
+/// let vec = writer.into_inner().unwrap();
+/// assert_eq!(vec, b"Hello");
+/// ```
+pub struct StackBufWriter<W: Write, const N: usize> {
+    inner: W,
+    buf: ArrayBuf<u8, N>,
+    /// Set while writing to `inner`, So that we don't flush again on drop if it panicked.
+    panicked: bool,
+}
+
+impl<W: Write, const N: usize> StackBufWriter<W, N> {
+    /// Creates a new `StackBufWriter` with a buffer of `N` bytes.
+    #[inline]
+    pub const fn new(inner: W) -> Self {
+        Self {
+            inner,
+            buf: ArrayBuf::new(),
+            panicked: false,
+        }
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns a reference to the internally buffered data.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Returns the number of bytes the internal buffer can hold without flushing.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Unwraps this `StackBufWriter`, Returning the underlying writer.
+    ///
+    /// The buffer is written out before returning the writer.
+    ///
+    /// # Errors
+    ///
+    /// An [`IntoInnerError`] will be returned if an error occurs while flushing the buffer.
+    pub fn into_inner(mut self) -> Result<W, IntoInnerError<Self>> {
+        match self.flush_buf() {
+            Err(e) => Err(IntoInnerError(self, e)),
+            Ok(()) => {
+                let this = mem::ManuallyDrop::new(self);
+                // SAFETY: `this` is never used or dropped again, `buf` doesn't need to be dropped.
+                Ok(unsafe { ptr::read(&this.inner) })
+            }
+        }
+    }
+
+    /// Send data in our local buffer into the inner writer, Looping as necessary until either it's all been sent or an error occurs.
+    ///
+    /// Data that was not written, Is kept at the front of the buffer.
+    fn flush_buf(&mut self) -> io::Result<()> {
+        let mut written = 0;
+        let len = self.buf.len();
+        let mut ret = Ok(());
+        while written < len {
+            self.panicked = true;
+            let r = self.inner.write(&self.buf[written..]);
+            self.panicked = false;
+
+            match r {
+                Ok(0) => {
+                    ret = Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write the buffered data",
+                    ));
+                    break;
+                }
+                Ok(n) => written += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => {
+                    ret = Err(e);
+                    break;
+                }
+            }
+        }
+        self.buf.drain(..written);
+        ret
+    }
+}
+
+impl<W: Write, const N: usize> Write for StackBufWriter<W, N> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.len() > self.buf.remaining_capacity() {
+            self.flush_buf()?;
+        }
+        if buf.len() >= N {
+            self.panicked = true;
+            let r = self.inner.write(buf);
+            self.panicked = false;
+            r
+        } else {
+            self.buf.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_buf()?;
+        self.inner.flush()
+    }
+}
+
+impl<W: Write + Seek, const N: usize> Seek for StackBufWriter<W, N> {
+    /// Seek to the offset, In bytes, In the underlying writer.
+    ///
+    /// Seeking always writes out the internal buffer before seeking.
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.flush_buf()?;
+        self.inner.seek(pos)
+    }
+}
+
+impl<W: Write, const N: usize> Drop for StackBufWriter<W, N> {
+    fn drop(&mut self) {
+        if !self.panicked {
+            // dtors should not panic, so we ignore a failed flush
+            let _r = self.flush_buf();
+        }
+    }
+}
+
+impl<W: Write + fmt::Debug, const N: usize> fmt::Debug for StackBufWriter<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StackBufWriter")
+            .field("writer", &self.inner)
+            .field("buffer", &format_args!("{}/{}", self.buf.len(), N))
+            .finish()
+    }
+}
+
+/// An error returned by [`StackBufWriter::into_inner`], Which combines an error that happened while writing out the buffer,
+/// And the buffered writer object which may be used to recover from the condition.
+#[derive(Debug)]
+pub struct IntoInnerError<W>(W, io::Error);
+
+impl<W> IntoInnerError<W> {
+    /// Returns the error which caused the call to `into_inner` to fail.
+    #[inline]
+    pub fn error(&self) -> &io::Error {
+        &self.1
+    }
+
+    /// Returns the buffered writer instance which generated the error.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+
+    /// Consumes the `IntoInnerError` and returns the error which caused the call to `into_inner` to fail.
+    #[inline]
+    pub fn into_error(self) -> io::Error {
+        self.1
+    }
+}
+
+impl<W> From<IntoInnerError<W>> for io::Error {
+    fn from(iie: IntoInnerError<W>) -> io::Error {
+        iie.1
+    }
+}
+
+impl<W> fmt::Display for IntoInnerError<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.1, f)
+    }
+}
+
+impl<W: fmt::Debug> error::Error for IntoInnerError<W> {}
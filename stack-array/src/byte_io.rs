@@ -0,0 +1,26 @@
+//! Byte I/O helpers, That only need `core`. Shared by the `std`, Async and `embedded-io` impls.
+
+use crate::*;
+
+/// Appends as many bytes as fit in the remaining capacity, Returns the number of bytes written.
+#[inline]
+pub(crate) fn write_within_capacity(arr: &mut impl Array<u8>, buf: &[u8]) -> usize {
+    let amt = cmp::min(buf.len(), arr.remaining_capacity());
+    arr.extend_from_slice(&buf[..amt]);
+    amt
+}
+
+/// Moves bytes from the front of the array into `buf`, Returns the number of bytes read.
+#[cfg(any(
+    feature = "tokio",
+    feature = "futures-io",
+    feature = "embedded-io",
+    feature = "embedded-io-async"
+))]
+#[inline]
+pub(crate) fn read_front(arr: &mut impl Array<u8>, buf: &mut [u8]) -> usize {
+    let amt = cmp::min(buf.len(), arr.len());
+    buf[..amt].copy_from_slice(&arr.as_slice()[..amt]);
+    arr.drain(..amt);
+    amt
+}
@@ -0,0 +1,123 @@
+use crate::*;
+use bytes::{buf::UninitSlice, Buf, BufMut};
+
+macro_rules! impl_buf_mut {
+    ($($(#[$meta:meta])* $ty:ident),*) => {$(
+        $(#[$meta])*
+        unsafe impl<const N: usize> BufMut for $ty<u8, N> {
+            #[inline]
+            fn remaining_mut(&self) -> usize {
+                self.remaining_capacity()
+            }
+
+            #[inline]
+            unsafe fn advance_mut(&mut self, cnt: usize) {
+                let remaining = self.remaining_capacity();
+                if cnt > remaining {
+                    panic!("advance out of bounds: the len is {remaining} but advancing by {cnt}");
+                }
+                self.set_len(self.len + cnt);
+            }
+
+            #[inline]
+            fn chunk_mut(&mut self) -> &mut UninitSlice {
+                UninitSlice::uninit(&mut self.buf[self.len..])
+            }
+
+            #[inline]
+            fn put_slice(&mut self, src: &[u8]) {
+                self.extend_from_slice(src);
+            }
+        }
+    )*};
+}
+
+impl_buf_mut! {
+    /// `BufMut` is implemented for `ArrayBuf<u8, N>` by appending to the array.
+    ///
+    /// [`remaining_mut`](BufMut::remaining_mut) is the remaining capacity, The array never grows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    /// use bytes::BufMut;
+    ///
+    /// let mut arr: ArrayBuf<u8, 8> = ArrayBuf::new();
+    /// arr.put_u16(0x0102);
+    /// arr.put_slice(b"abc");
+    ///
+    /// assert_eq!(arr.as_slice(), b"\x01\x02abc");
+    /// assert_eq!(arr.remaining_mut(), 3);
+    /// ```
+    ArrayBuf
+}
+
+#[cfg(feature = "std")]
+impl_buf_mut! {
+    /// Like `ArrayBuf<u8, N>`, `BufMut` appends to the array, Without growing it.
+    HeapArrayBuf
+}
+
+impl<const N: usize> ArrayBuf<u8, N> {
+    /// Returns a reading view of the array, That implement [`Buf`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    /// use bytes::Buf;
+    ///
+    /// let arr = ArrayBuf::from([0, 1, 2, 3, 4]);
+    /// let mut reader = arr.reader();
+    ///
+    /// assert_eq!(reader.get_u16(), 1);
+    /// assert_eq!(reader.chunk(), [2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn reader(&self) -> BytesReader<'_, N> {
+        BytesReader { arr: self, pos: 0 }
+    }
+}
+
+/// A reading view of an [`ArrayBuf<u8, N>`], Created by [`ArrayBuf::reader`].
+#[derive(Debug, Clone)]
+pub struct BytesReader<'a, const N: usize> {
+    arr: &'a ArrayBuf<u8, N>,
+    pos: usize,
+}
+
+impl<'a, const N: usize> BytesReader<'a, N> {
+    /// Returns the number of bytes that have been read.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the underlying array.
+    #[inline]
+    pub fn get_ref(&self) -> &'a ArrayBuf<u8, N> {
+        self.arr
+    }
+}
+
+impl<const N: usize> Buf for BytesReader<'_, N> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.arr.len - self.pos
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        &self.arr[self.pos..]
+    }
+
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        let remaining = self.remaining();
+        if cnt > remaining {
+            panic!("advance out of bounds: the len is {remaining} but advancing by {cnt}");
+        }
+        self.pos += cnt;
+    }
+}
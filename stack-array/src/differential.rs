@@ -0,0 +1,142 @@
+//! A differential checker, That compares an [`Array`] implementation against `Vec<T>`.
+//!
+//! A sequence of [`Op`] is applied to both the array and a `Vec<T>` model, Through the [`Array`] trait.
+//! After every operation, The contents, And whatever the operation returned or observed, Must be identical.
+//!
+//! Operations are interpreted relative to the current length, So every sequence of `Op` is valid:
+//! Indices are wrapped into bounds, And operations that would overflow the capacity are skipped.
+//! This makes it easy to drive from a fuzzer or a property test.
+//!
+//! # Examples
+//!
+//! ```
+//! use stack_array::*;
+//! use stack_array::differential::{check, Op};
+//!
+//! check(ArrayBuf::<u8, 4>::new(), [
+//!     Op::Push(1),
+//!     Op::Insert(0, 2),
+//!     Op::Append(vec![3, 3, 4]), // skipped, Would overflow
+//!     Op::Append(vec![3, 3]),
+//!     Op::DedupBy(u64::MAX),
+//!     Op::Drain(1, 1),
+//! ]);
+//! ```
+
+use crate::*;
+
+/// An operation of the [`Array`] trait.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op<T> {
+    /// [`Array::push`], Skipped if the array is full.
+    Push(T),
+    /// [`Array::insert`] at `index % (len + 1)`, Skipped if the array is full.
+    Insert(usize, T),
+    /// [`Array::remove`] at `index % len`, Skipped if the array is empty.
+    Remove(usize),
+    /// [`Array::drain`] the range `start..start + count`, Both are wrapped into bounds.
+    Drain(usize, usize),
+    /// [`Array::retain`], The `i`th visited element is kept if the `i % 64` bit of the mask is set.
+    Retain(u64),
+    /// [`Array::dedup_by`], Elements are in the same bucket if they are equal,
+    /// Or the `i % 64` bit of the mask is set on the `i`th call.
+    DedupBy(u64),
+    /// [`Array::append`], Skipped if the array doesn't have enough capacity.
+    Append(Vec<T>),
+    /// [`Array::truncate`] to `len % (capacity + 1)`.
+    Truncate(usize),
+}
+
+/// Apply `ops` to `arr` and a `Vec<T>` model, Asserting that they behave identically.
+///
+/// Returns the array after all the operations.
+///
+/// # Panics
+///
+/// Panics if the array and the model diverge, Or if the array is ever longer than its capacity.
+pub fn check<T, A>(mut arr: A, ops: impl IntoIterator<Item = Op<T>>) -> A
+where
+    T: Clone + PartialEq + fmt::Debug,
+    A: Array<T>,
+{
+    let capacity = arr.capacity();
+    let mut model = arr.as_slice().to_vec();
+
+    for (step, op) in ops.into_iter().enumerate() {
+        let observed = apply(&mut arr, capacity, &op);
+        let expected = apply(&mut model, capacity, &op);
+
+        assert_eq!(
+            observed, expected,
+            "step {step}: {op:?} returned different values"
+        );
+        assert_eq!(
+            arr.as_slice(),
+            model.as_slice(),
+            "step {step}: {op:?} diverged"
+        );
+        assert!(
+            arr.len() <= capacity,
+            "step {step}: {op:?} exceeded the capacity"
+        );
+    }
+    arr
+}
+
+/// Apply `op` to `arr`, Returns the elements that were removed or observed by the operation.
+fn apply<T, A>(arr: &mut A, capacity: usize, op: &Op<T>) -> Vec<T>
+where
+    T: Clone + PartialEq,
+    A: Array<T>,
+{
+    let len = arr.len();
+    let mut observed = Vec::new();
+    match op {
+        Op::Push(value) => {
+            if len < capacity {
+                arr.push(value.clone());
+            }
+        }
+        Op::Insert(index, value) => {
+            if len < capacity {
+                arr.insert(index % (len + 1), value.clone());
+            }
+        }
+        Op::Remove(index) => {
+            if len > 0 {
+                observed.push(arr.remove(index % len));
+            }
+        }
+        Op::Drain(start, count) => {
+            let start = start % (len + 1);
+            let end = start + count % (len - start + 1);
+            observed.extend(arr.drain(start..end));
+        }
+        Op::Retain(mask) => {
+            let mut i = 0;
+            arr.retain(|value| {
+                observed.push(value.clone());
+                let keep = mask & (1 << (i % 64)) != 0;
+                i += 1;
+                keep
+            });
+        }
+        Op::DedupBy(mask) => {
+            let mut i = 0;
+            arr.dedup_by(|a, b| {
+                observed.push(a.clone());
+                observed.push(b.clone());
+                let same = a == b || mask & (1 << (i % 64)) != 0;
+                i += 1;
+                same
+            });
+        }
+        Op::Append(other) => {
+            if len + other.len() <= capacity {
+                arr.append(&mut other.clone());
+            }
+        }
+        Op::Truncate(new_len) => arr.truncate(new_len % capacity.saturating_add(1)),
+    }
+    observed
+}
@@ -168,4 +168,6 @@ impl<T, A: Array<T>> Drop for Drain<'_, T, A> {
     }
 }
 
+impl<T, A: Array<T>> ExactSizeIterator for Drain<'_, T, A> {}
+
 impl<T, A: Array<T>> core::iter::FusedIterator for Drain<'_, T, A> {}
@@ -0,0 +1,96 @@
+//! `embedded-io` traits for `ArrayBuf<u8, N>`, For drivers that are generic over them.
+//!
+//! Unlike `std::io::Write`, A write to a full array fails with [`CapacityError`]
+//! (Of kind `OutOfMemory`), Instead of returning `Ok(0)`.
+//! A write larger than the remaining capacity is still a short write.
+
+use crate::{
+    byte_io::{read_front, write_within_capacity},
+    *,
+};
+#[cfg(feature = "embedded-io")]
+use embedded_io::{Error, ErrorKind, ErrorType};
+#[cfg(not(feature = "embedded-io"))]
+use embedded_io_async::{Error, ErrorKind, ErrorType};
+
+impl Error for CapacityError {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::OutOfMemory
+    }
+}
+
+impl<const N: usize> ErrorType for ArrayBuf<u8, N> {
+    type Error = CapacityError;
+}
+
+/// Appends as many bytes as fit, Or fails if the array is full and `buf` isn't empty.
+#[inline]
+fn try_write<const N: usize>(
+    arr: &mut ArrayBuf<u8, N>,
+    buf: &[u8],
+) -> Result<usize, CapacityError> {
+    if arr.is_full() && !buf.is_empty() {
+        return Err(CapacityError::new(N + buf.len(), N));
+    }
+    Ok(write_within_capacity(arr, buf))
+}
+
+/// Appends to the array, Without growing it.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+/// use embedded_io::{Error, ErrorKind, Write};
+///
+/// let mut arr: ArrayBuf<u8, 4> = ArrayBuf::new();
+/// assert_eq!(arr.write(b"Hello").unwrap(), 4);
+///
+/// let err = arr.write(b"World").unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::OutOfMemory);
+/// ```
+#[cfg(feature = "embedded-io")]
+impl<const N: usize> embedded_io::Write for ArrayBuf<u8, N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        try_write(self, buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Consumes bytes from the front of the array, An empty array reads as end of file.
+#[cfg(feature = "embedded-io")]
+impl<const N: usize> embedded_io::Read for ArrayBuf<u8, N> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Ok(read_front(self, buf))
+    }
+}
+
+/// Like the blocking `Write`, It never waits.
+#[cfg(feature = "embedded-io-async")]
+impl<const N: usize> embedded_io_async::Write for ArrayBuf<u8, N> {
+    #[inline]
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        try_write(self, buf)
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Like the blocking `Read`, It never waits.
+#[cfg(feature = "embedded-io-async")]
+impl<const N: usize> embedded_io_async::Read for ArrayBuf<u8, N> {
+    #[inline]
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Ok(read_front(self, buf))
+    }
+}
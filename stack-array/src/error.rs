@@ -0,0 +1,51 @@
+use crate::*;
+
+/// An error returned by the fallible methods of [`Array`], When the array doesn't have enough capacity.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+///
+/// let mut arr: ArrayBuf<u8, 4> = ArrayBuf::new();
+/// let err = arr.try_resize(5, 0).unwrap_err();
+///
+/// assert_eq!((err.required(), err.capacity()), (5, 4));
+/// assert!(arr.is_empty());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError {
+    required: usize,
+    capacity: usize,
+}
+
+impl CapacityError {
+    #[inline]
+    pub(crate) fn new(required: usize, capacity: usize) -> Self {
+        Self { required, capacity }
+    }
+
+    /// Returns the length that was required.
+    #[inline]
+    pub fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Returns the capacity of the array.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Array is full, Max capacity: {}, But got: {}",
+            self.capacity, self.required
+        )
+    }
+}
+
+impl core::error::Error for CapacityError {}
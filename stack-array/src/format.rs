@@ -0,0 +1,9 @@
+use crate::*;
+
+/// Logs the elements as a `{=[?]}` slice, Each element is encoded with its own `Format` impl,
+/// And the text is only rendered by the host that decodes the log.
+impl<T: defmt::Format, const N: usize> defmt::Format for ArrayBuf<T, N> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=[?]}", self.as_slice())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{differential::Op, *};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+macro_rules! impl_arbitrary {
+    ($($ty:ident),*) => {$(
+        /// Like `Vec<T>`, But stop generating elements once the array is full.
+        impl<'a, T: Arbitrary<'a>, const N: usize> Arbitrary<'a> for $ty<T, N> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                let mut arr = $ty::new();
+                for value in u.arbitrary_iter()? {
+                    if arr.is_full() {
+                        break;
+                    }
+                    arr.push(value?);
+                }
+                Ok(arr)
+            }
+
+            fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+                let mut arr = $ty::new();
+                for value in u.arbitrary_take_rest_iter()? {
+                    if arr.is_full() {
+                        break;
+                    }
+                    arr.push(value?);
+                }
+                Ok(arr)
+            }
+
+            #[inline]
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                (0, None)
+            }
+        }
+    )*};
+}
+
+impl_arbitrary!(ArrayBuf, HeapArrayBuf);
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Op<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.choose_index(8)? {
+            0 => Op::Push(u.arbitrary()?),
+            1 => Op::Insert(u.arbitrary()?, u.arbitrary()?),
+            2 => Op::Remove(u.arbitrary()?),
+            3 => Op::Drain(u.arbitrary()?, u.arbitrary()?),
+            4 => Op::Retain(u.arbitrary()?),
+            5 => Op::DedupBy(u.arbitrary()?),
+            6 => Op::Append(u.arbitrary()?),
+            _ => Op::Truncate(u.arbitrary()?),
+        })
+    }
+}
@@ -0,0 +1,341 @@
+use crate::*;
+
+/// Like [`ArrayBuf`], But the `N` slots are allocated on the heap.
+///
+/// Useful for large capacities that would overflow the stack. The buffer is allocated once,
+/// Directly on the heap, And is never reallocated.
+///
+/// Like `ArrayBuf`, The capacity is exactly `N`, Even if `T` is a zero-sized type or `N` is `0`.
+/// Nothing is allocated in that case.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+///
+/// let mut arr: HeapArrayBuf<u64, 1_000_000> = HeapArrayBuf::new();
+/// arr.push(1);
+/// arr.push(2);
+///
+/// assert_eq!(arr.len(), 2);
+/// assert_eq!(arr.capacity(), 1_000_000);
+/// ```
+pub struct HeapArrayBuf<T, const N: usize> {
+    pub(crate) len: usize,
+    pub(crate) buf: Box<[MaybeUninit<T>; N]>,
+}
+
+impl<T, const N: usize> HeapArrayBuf<T, N> {
+    /// Constructs a new, Empty `HeapArrayBuf`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: HeapArrayBuf<u8, 64> = HeapArrayBuf::new();
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            // SAFETY: An array of `MaybeUninit` doesn't require initialization.
+            buf: unsafe { Box::new_uninit().assume_init() },
+        }
+    }
+
+    /// Returns `true`, If the array is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let arr: HeapArrayBuf<_, 3> = HeapArrayBuf::from([1, 2, 3]);
+    /// assert!(arr.is_full());
+    /// ```
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len >= N
+    }
+
+    /// Clones the elements of `values` into a new array, Or returns `values` back if it has more than `N` elements.
+    ///
+    /// See [`ArrayBuf::try_from_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let arr = HeapArrayBuf::<_, 4>::try_from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!(arr, [1, 2, 3]);
+    ///
+    /// assert_eq!(HeapArrayBuf::<_, 2>::try_from_slice(&[1, 2, 3]), Err(&[1, 2, 3][..]));
+    /// ```
+    pub fn try_from_slice(values: &[T]) -> Result<Self, &[T]>
+    where
+        T: Clone,
+    {
+        if values.len() > N {
+            return Err(values);
+        }
+        let mut arr = Self::new();
+        for value in values {
+            arr.push(value.clone());
+        }
+        Ok(arr)
+    }
+
+    /// Splits the array into two at the given index.
+    ///
+    /// Returns a newly allocated array containing the elements in the range `[at, len)`.
+    /// After the call, The original array will be left containing the elements `[0, at)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`, Or the new array can't hold `len - at` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr = HeapArrayBuf::from([1, 2, 3, 4]);
+    /// let tail: HeapArrayBuf<_, 2> = arr.split_off(2);
+    ///
+    /// assert_eq!(arr[..], [1, 2]);
+    /// assert_eq!(tail, [3, 4]);
+    /// ```
+    pub fn split_off<const M: usize>(&mut self, at: usize) -> HeapArrayBuf<T, M> {
+        #[cold]
+        #[inline(never)]
+        fn assert_failed(at: usize, len: usize) -> ! {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+        if at > self.len {
+            assert_failed(at, self.len);
+        }
+        let mut other = HeapArrayBuf::new();
+        other.ensure_capacity(self.len - at);
+        unsafe {
+            // SAFETY: `other` is empty, And has the capacity for `len - at` elements.
+            let count = self.len - at;
+            self.set_len(at);
+            ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), count);
+            other.set_len(count);
+        }
+        other
+    }
+
+    /// Moves all the elements of `self` and `other` into a new array with capacity `O`.
+    ///
+    /// `O` is checked at compile-time, It must be at least `N + M`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let arr: HeapArrayBuf<_, 5> = HeapArrayBuf::from([1, 2]).concat(HeapArrayBuf::from([3, 4, 5]));
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use stack_array::*;
+    ///
+    /// let arr: HeapArrayBuf<_, 4> = HeapArrayBuf::from([1, 2]).concat(HeapArrayBuf::from([3, 4, 5]));
+    /// ```
+    pub fn concat<const M: usize, const O: usize>(
+        mut self,
+        mut other: HeapArrayBuf<T, M>,
+    ) -> HeapArrayBuf<T, O> {
+        const {
+            assert!(
+                N + M <= O,
+                "`HeapArrayBuf` can't hold the elements of both arrays"
+            )
+        };
+        let mut arr = HeapArrayBuf::new();
+        unsafe {
+            // SAFETY: `arr` can hold `N + M` elements, And ownership of the elements is moved.
+            let (len, other_len) = (self.len, other.len);
+            self.set_len(0);
+            other.set_len(0);
+            ptr::copy_nonoverlapping(self.as_ptr(), arr.as_mut_ptr(), len);
+            ptr::copy_nonoverlapping(other.as_ptr(), arr.as_mut_ptr().add(len), other_len);
+            arr.set_len(len + other_len);
+        }
+        arr
+    }
+}
+
+// SAFETY: `buf` is a heap allocation of `N` slots, That is never reallocated,
+// And the first `len <= N` slots are initialized.
+unsafe impl<T, const N: usize> Array<T> for HeapArrayBuf<T, N> {
+    #[inline]
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.buf.as_ptr() as _
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.buf.as_mut_ptr() as _
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity());
+        self.len = new_len;
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T, const N: usize> Drop for HeapArrayBuf<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for HeapArrayBuf<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Moves the elements from `src` to `dst`, Without running any destructor.
+///
+/// # Safety
+///
+/// `dst` must be empty, And both must have the same capacity.
+#[inline]
+unsafe fn move_all<T>(src: &mut impl Array<T>, dst: &mut impl Array<T>) {
+    let len = src.len();
+    ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), len);
+    src.set_len(0);
+    dst.set_len(len);
+}
+
+/// Moves the elements to the heap, Only the initialized elements are copied.
+impl<T, const N: usize> From<ArrayBuf<T, N>> for HeapArrayBuf<T, N> {
+    fn from(mut arr: ArrayBuf<T, N>) -> Self {
+        let mut heap = Self::new();
+        // SAFETY: `heap` is empty, And both have a capacity of `N`.
+        unsafe { move_all(&mut arr, &mut heap) };
+        heap
+    }
+}
+
+/// Moves the elements to the stack, Only the initialized elements are copied.
+impl<T, const N: usize> From<HeapArrayBuf<T, N>> for ArrayBuf<T, N> {
+    fn from(mut heap: HeapArrayBuf<T, N>) -> Self {
+        let mut arr = Self::new();
+        // SAFETY: `arr` is empty, And both have a capacity of `N`.
+        unsafe { move_all(&mut heap, &mut arr) };
+        arr
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for HeapArrayBuf<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for HeapArrayBuf<T, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> Deref for HeapArrayBuf<T, N> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for HeapArrayBuf<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Copy, const N: usize> From<&[T]> for HeapArrayBuf<T, N> {
+    fn from(values: &[T]) -> Self {
+        let mut array = Self::new();
+        array.extend_from_slice(values);
+        array
+    }
+}
+
+impl<T: Copy, const N: usize> From<[T; N]> for HeapArrayBuf<T, N> {
+    fn from(values: [T; N]) -> Self {
+        let mut array = Self::new();
+        array.extend_from_slice(values);
+        array
+    }
+}
+
+impl<T, I: SliceIndex<[T]>, const N: usize> Index<I> for HeapArrayBuf<T, N> {
+    type Output = I::Output;
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(&**self, index)
+    }
+}
+
+impl<T, I: SliceIndex<[T]>, const N: usize> IndexMut<I> for HeapArrayBuf<T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(&mut **self, index)
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for HeapArrayBuf<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for HeapArrayBuf<T, N> {
+    fn borrow(&self) -> &[T] {
+        &self[..]
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for HeapArrayBuf<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        &mut self[..]
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for HeapArrayBuf<T, N> {}
+
+/// Implements ordering of vectors, [lexicographically](core::cmp::Ord#lexicographical-comparison).
+impl<T: Ord, const N: usize> cmp::Ord for HeapArrayBuf<T, N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+impl<T: hash::Hash, const N: usize> hash::Hash for HeapArrayBuf<T, N> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        hash::Hash::hash(&**self, state)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::*;
+
+/// A circular buffer that keeps the last `N` written elements, Stored inline.
+///
+/// Unlike [`ArrayBuf`], Which rejects new elements when full, Writing to a full `HistoryBuf`
+/// drops the oldest element and replaces it. So [`write`](HistoryBuf::write) never fails.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+///
+/// let mut history: HistoryBuf<u32, 3> = HistoryBuf::new();
+/// history.extend([1, 2, 3, 4]);
+///
+/// assert_eq!(history.recent(), Some(&4));
+/// assert!(history.oldest_ordered().eq(&[2, 3, 4]));
+/// assert_eq!(history.mean(), Some(3.0));
+/// ```
+pub struct HistoryBuf<T, const N: usize> {
+    write_at: usize,
+    filled: bool,
+    buf: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> HistoryBuf<T, N> {
+    /// Constructs a new, Empty `HistoryBuf`
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            write_at: 0,
+            filled: false,
+            buf: unsafe { MaybeUninit::uninit().assume_init() },
+        }
+    }
+
+    /// Returns the number of elements, That is at most `N`.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        if self.filled {
+            N
+        } else {
+            self.write_at
+        }
+    }
+
+    /// Returns the number of elements the buffer can hold, That is `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true`, If the buffer contains no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true`, If the buffer is full, So the next write overwrites the oldest element.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len() >= N
+    }
+
+    /// Writes an element, Dropping the oldest element if the buffer is full.
+    ///
+    /// If `N` is `0`, The element is dropped immediately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut history: HistoryBuf<_, 2> = HistoryBuf::new();
+    /// history.write(1);
+    /// history.write(2);
+    /// history.write(3);
+    /// assert_eq!(history.as_slices(), (&[2][..], &[3][..]));
+    /// ```
+    pub fn write(&mut self, value: T) {
+        if N == 0 {
+            return drop(value);
+        }
+        let slot = self.buf[self.write_at].as_mut_ptr();
+        let old = if self.filled {
+            Some(unsafe { ptr::replace(slot, value) })
+        } else {
+            unsafe { ptr::write(slot, value) };
+            None
+        };
+        self.write_at += 1;
+        if self.write_at == N {
+            self.write_at = 0;
+            self.filled = true;
+        }
+        // Drop it last, So a panicking destructor leaves the buffer in a valid state.
+        drop(old);
+    }
+
+    /// Clones and writes all the elements of a slice, In order.
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.extend(other.iter().cloned())
+    }
+
+    /// Returns a reference to the most recently written element, Or `None` if the buffer is empty.
+    #[inline]
+    pub fn recent(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = if self.write_at == 0 { N } else { self.write_at } - 1;
+        Some(unsafe { self.buf[index].assume_init_ref() })
+    }
+
+    /// Returns a reference to the oldest element, Or `None` if the buffer is empty.
+    #[inline]
+    pub fn oldest(&self) -> Option<&T> {
+        let (first, second) = self.as_slices();
+        first.first().or(second.first())
+    }
+
+    /// Returns the elements as two slices, From the oldest to the most recent.
+    ///
+    /// The second slice is empty until the buffer has wrapped around.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let ptr = self.buf.as_ptr() as *const T;
+        unsafe {
+            if self.filled {
+                (
+                    slice::from_raw_parts(ptr.add(self.write_at), N - self.write_at),
+                    slice::from_raw_parts(ptr, self.write_at),
+                )
+            } else {
+                (slice::from_raw_parts(ptr, self.write_at), &[])
+            }
+        }
+    }
+
+    /// Returns the elements as two mutable slices, From the oldest to the most recent.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let ptr = self.buf.as_mut_ptr() as *mut T;
+        unsafe {
+            if self.filled {
+                (
+                    slice::from_raw_parts_mut(ptr.add(self.write_at), N - self.write_at),
+                    slice::from_raw_parts_mut(ptr, self.write_at),
+                )
+            } else {
+                (slice::from_raw_parts_mut(ptr, self.write_at), &mut [])
+            }
+        }
+    }
+
+    /// Returns an iterator over the elements, From the oldest to the most recent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut history: HistoryBuf<_, 3> = HistoryBuf::new();
+    /// history.extend_from_slice(&[1, 2, 3, 4, 5]);
+    /// assert!(history.oldest_ordered().eq(&[3, 4, 5]));
+    /// assert!(history.oldest_ordered().rev().eq(&[5, 4, 3]));
+    /// ```
+    #[inline]
+    pub fn oldest_ordered(&self) -> OldestOrdered<'_, T> {
+        let (first, second) = self.as_slices();
+        OldestOrdered {
+            first: first.iter(),
+            second: second.iter(),
+        }
+    }
+
+    /// Removes all the elements, Dropping them.
+    pub fn clear(&mut self) {
+        let len = self.len();
+        self.write_at = 0;
+        self.filled = false;
+        let elems = ptr::slice_from_raw_parts_mut(self.buf.as_mut_ptr() as *mut T, len);
+        unsafe { ptr::drop_in_place(elems) };
+    }
+}
+
+impl<T: Copy + Into<f64>, const N: usize> HistoryBuf<T, N> {
+    /// Returns the arithmetic mean of the elements, Or `None` if the buffer is empty.
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let sum: f64 = self.oldest_ordered().map(|&v| v.into()).sum();
+        Some(sum / self.len() as f64)
+    }
+
+    /// Returns the smallest element as `f64`, Or `None` if the buffer is empty.
+    ///
+    /// `NaN` values are ignored, Unless every element is `NaN`.
+    pub fn min(&self) -> Option<f64> {
+        self.oldest_ordered().map(|&v| v.into()).reduce(f64::min)
+    }
+
+    /// Returns the largest element as `f64`, Or `None` if the buffer is empty.
+    ///
+    /// `NaN` values are ignored, Unless every element is `NaN`.
+    pub fn max(&self) -> Option<f64> {
+        self.oldest_ordered().map(|&v| v.into()).reduce(f64::max)
+    }
+}
+
+impl<T, const N: usize> Drop for HistoryBuf<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for HistoryBuf<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for HistoryBuf<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.write(value);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a, const N: usize> Extend<&'a T> for HistoryBuf<T, N> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for HistoryBuf<T, N> {
+    fn clone(&self) -> Self {
+        let mut history = Self::new();
+        history.extend(self.oldest_ordered().cloned());
+        history
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for HistoryBuf<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.oldest_ordered()).finish()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a HistoryBuf<T, N> {
+    type Item = &'a T;
+    type IntoIter = OldestOrdered<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.oldest_ordered()
+    }
+}
+
+/// An iterator over the elements of a [`HistoryBuf`], From the oldest to the most recent.
+///
+/// This struct is created by [`HistoryBuf::oldest_ordered`].
+#[derive(Clone)]
+pub struct OldestOrdered<'a, T> {
+    first: slice::Iter<'a, T>,
+    second: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for OldestOrdered<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.first.next().or_else(|| self.second.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.first.len() + self.second.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for OldestOrdered<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.second.next_back().or_else(|| self.first.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for OldestOrdered<'_, T> {}
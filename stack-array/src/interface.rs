@@ -1,6 +1,35 @@
 use crate::{drain::slice_range, *};
 
-pub trait Array<T>: AsRef<[T]> + AsMut<[T]> + Default {
+/// A contiguous growable array type, With a fixed capacity, Such as [`ArrayBuf`] and `Vec<T>`.
+///
+/// Most of the methods are provided, And they are implemented with raw pointer operations,
+/// That trust the required methods.
+///
+/// # Safety
+///
+/// The implementation must uphold the following invariants,
+/// Otherwise the provided methods may cause undefined behavior:
+///
+/// - [`len()`](Array::len) is always less than or equal to [`capacity()`](Array::capacity).
+/// - [`as_ptr()`](Array::as_ptr) and [`as_mut_ptr()`](Array::as_mut_ptr) return the same non-null, Properly aligned pointer,
+///   That is valid for reads and writes of `capacity()` elements of `T`.
+/// - The first `len()` elements of the buffer are initialized, And the array owns them.
+/// - [`set_len(new_len)`](Array::set_len) sets the length to `new_len`, Without reading, Writing or dropping any element.
+/// - The buffer and `capacity()` doesn't change, Unless the array is modified by a method other than
+///   `set_len()`, Or by writes through `as_mut_ptr()`.
+/// - If [`ensure_capacity(total_len)`](Array::ensure_capacity) returns, Or
+///   [`try_ensure_capacity(total_len)`](Array::try_ensure_capacity) returns `Ok(())`,
+///   `capacity()` is greater than or equal to `total_len`. The provided methods rely on it
+///   to write past `len()`, Without checking `capacity()` again.
+/// - `as_ref()` and `as_mut()` return the first `len()` elements of the buffer.
+/// - Overridden provided methods must behave as documented, And keep these invariants.
+///
+/// # Compatibility
+///
+/// Since `0.5`, `Array` no longer requires `Default`, So that a `Vec<T, A>` with a stateful allocator,
+/// Such as `StackArena` (With the `allocator-api2` feature), Can implement it. Use an `A: Array<T> + Default` bound,
+/// If you need to construct an empty array.
+pub unsafe trait Array<T>: AsRef<[T]> + AsMut<[T]> + Sized {
     /// Returns the number of elements the array can hold.
     ///
     /// # Examples
@@ -78,7 +107,7 @@ pub trait Array<T>: AsRef<[T]> + AsMut<[T]> + Default {
     /// - `new_len` must be less than or equal to [`capacity()`].
     /// - The elements at `old_len..new_len` must be initialized.
     ///
-    /// [`capacity()`]: Vec::capacity
+    /// [`capacity()`]: Array::capacity
     unsafe fn set_len(&mut self, len: usize);
 
     /// Extracts a slice containing the entire array.
@@ -292,6 +321,76 @@ pub trait Array<T>: AsRef<[T]> + AsMut<[T]> + Default {
         retain_mut(self, |elem| f(elem))
     }
 
+    /// Retains only the elements specified by the predicate, Passing a mutable reference to it.
+    ///
+    /// In other words, remove all elements `e` such that `f(&mut e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr = ArrayBuf::from([1, 2, 3, 4]);
+    /// arr.retain_mut(|x| if *x <= 3 {
+    ///     *x += 1;
+    ///     true
+    /// } else {
+    ///     false
+    /// });
+    /// assert_eq!(arr[..], [2, 3, 4]);
+    /// ```
+    #[inline]
+    fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        retain_mut(self, f)
+    }
+
+    /// Like [`retain_mut`](Array::retain_mut), But `f` also gets the original index of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr = ArrayBuf::from(['a', 'b', 'c', 'd']);
+    /// arr.retain_with_index(|i, _| i % 2 == 0);
+    /// assert_eq!(arr[..], ['a', 'c']);
+    /// ```
+    #[inline]
+    fn retain_with_index<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut T) -> bool,
+    {
+        retain_with(self, |i, elem| Some(f(i, elem)))
+    }
+
+    /// Like [`retain_mut`](Array::retain_mut), But stops as soon as `f` returns `None`.
+    ///
+    /// `Some(keep)` decides whether to keep the element, `None` keeps the current element
+    /// and all the elements after it, Without visiting them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr = ArrayBuf::from([1, 2, 3, 10, 4, 5]);
+    /// // Remove even numbers, Until the first number greater than 9.
+    /// arr.retain_while(|x| (*x < 10).then_some(*x % 2 != 0));
+    /// assert_eq!(arr[..], [1, 3, 10, 4, 5]);
+    /// ```
+    #[inline]
+    fn retain_while<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> Option<bool>,
+    {
+        retain_with(self, |_, elem| f(elem))
+    }
+
     fn drain<R>(&mut self, range: R) -> Drain<'_, T, Self>
     where
         R: RangeBounds<usize>,
@@ -584,6 +683,13 @@ pub trait Array<T>: AsRef<[T]> + AsMut<[T]> + Default {
 
     //============================================================
 
+    /// Ensures that the array can hold `total_len` elements.
+    ///
+    /// On return, [`capacity()`](Array::capacity) is greater than or equal to `total_len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array can't hold `total_len` elements.
     #[inline]
     fn ensure_capacity(&mut self, total_len: usize) {
         if total_len > self.capacity() {
@@ -633,4 +739,559 @@ pub trait Array<T>: AsRef<[T]> + AsMut<[T]> + Default {
             self.set_len(total_len);
         }
     }
+
+    /// Like [`ensure_capacity`](Array::ensure_capacity), But returns an error instead of panicking.
+    ///
+    /// On `Ok(())`, [`capacity()`](Array::capacity) is greater than or equal to `total_len`.
+    #[inline]
+    fn try_ensure_capacity(&mut self, total_len: usize) -> Result<(), CapacityError> {
+        if total_len > self.capacity() {
+            return Err(CapacityError::new(total_len, self.capacity()));
+        }
+        Ok(())
+    }
+
+    /// Resizes the array in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, The array is extended by the difference,
+    /// With each additional slot filled with `value`. If `new_len` is less than `len`, The array is truncated.
+    ///
+    /// If `clone` panics, The elements cloned so far are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is greater than the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: ArrayBuf<_, 4> = ArrayBuf::from(["hello"].as_slice());
+    /// arr.resize(3, "world");
+    /// assert_eq!(arr[..], ["hello", "world", "world"]);
+    ///
+    /// arr.resize(1, "");
+    /// assert_eq!(arr[..], ["hello"]);
+    /// ```
+    fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let len = self.len();
+        if new_len <= len {
+            return self.truncate(new_len);
+        }
+        self.ensure_capacity(new_len);
+        for _ in len + 1..new_len {
+            // SAFETY: Capacity is checked, Each element is counted as soon as it is written.
+            unsafe { push_unchecked(self, value.clone()) };
+        }
+        unsafe { push_unchecked(self, value) };
+    }
+
+    /// Like [`resize`](Array::resize), But returns an error instead of panicking.
+    ///
+    /// The array is unchanged on error.
+    #[inline]
+    fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        self.try_ensure_capacity(new_len)?;
+        self.resize(new_len, value);
+        Ok(())
+    }
+
+    /// Resizes the array in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, The array is extended by the difference,
+    /// With each additional slot filled with the result of calling the closure `f`.
+    /// If `new_len` is less than `len`, The array is truncated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is greater than the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: ArrayBuf<_, 4> = ArrayBuf::new();
+    /// let mut p = 1;
+    /// arr.resize_with(4, || { p *= 2; p });
+    /// assert_eq!(arr, [2, 4, 8, 16]);
+    /// ```
+    fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        let len = self.len();
+        if new_len <= len {
+            return self.truncate(new_len);
+        }
+        self.ensure_capacity(new_len);
+        for _ in len..new_len {
+            // SAFETY: Capacity is checked, Each element is counted as soon as it is written.
+            unsafe { push_unchecked(self, f()) };
+        }
+    }
+
+    /// Like [`resize_with`](Array::resize_with), But returns an error instead of panicking.
+    ///
+    /// The array is unchanged on error.
+    #[inline]
+    fn try_resize_with<F>(&mut self, new_len: usize, f: F) -> Result<(), CapacityError>
+    where
+        F: FnMut() -> T,
+    {
+        self.try_ensure_capacity(new_len)?;
+        self.resize_with(new_len, f);
+        Ok(())
+    }
+
+    /// Copies elements from `src` range to the end of the array.
+    ///
+    /// If `clone` panics, The elements cloned so far are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, If the end point is greater than the length of the array,
+    /// Or if the array doesn't have enough capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: ArrayBuf<_, 8> = ArrayBuf::from([0, 1, 2, 3].as_slice());
+    /// arr.extend_from_within(2..);
+    /// assert_eq!(arr[..], [0, 1, 2, 3, 2, 3]);
+    /// ```
+    fn extend_from_within<R>(&mut self, src: R)
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let Range { start, end } = slice_range(src, ..self.len());
+        self.ensure_capacity(self.len() + (end - start));
+        for i in start..end {
+            unsafe {
+                // SAFETY: `i < end <= len`, And capacity is checked.
+                let value = (*self.as_ptr().add(i)).clone();
+                push_unchecked(self, value);
+            }
+        }
+    }
+
+    /// Like [`extend_from_within`](Array::extend_from_within), But returns an error instead of panicking,
+    /// If the array doesn't have enough capacity.
+    ///
+    /// The array is unchanged on error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, Or if the end point is greater than the length of the array.
+    #[inline]
+    fn try_extend_from_within<R>(&mut self, src: R) -> Result<(), CapacityError>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let range = slice_range(src, ..self.len());
+        self.try_ensure_capacity(self.len() + range.len())?;
+        self.extend_from_within(range);
+        Ok(())
+    }
+
+    /// Inserts clones of all the elements of `other` at position `index`, Shifting all elements after it to the right.
+    ///
+    /// If `clone` panics, The elements cloned so far are dropped, And the array is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`, Or if the array doesn't have enough capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: ArrayBuf<_, 5> = ArrayBuf::from([1, 5].as_slice());
+    /// arr.insert_slice(1, &[2, 3, 4]);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    fn insert_slice(&mut self, index: usize, other: &[T])
+    where
+        T: Clone,
+    {
+        let len = self.len();
+        assert_insert_index(index, len);
+        self.ensure_capacity(len + other.len());
+        let guard = TruncateOnDrop::new(self, len);
+        for value in other {
+            // SAFETY: Capacity is checked, Each element is counted as soon as it is written.
+            unsafe { push_unchecked(guard.arr, value.clone()) };
+        }
+        mem::forget(guard);
+        self.as_mut_slice()[index..].rotate_right(other.len());
+    }
+
+    /// Like [`insert_slice`](Array::insert_slice), But returns an error instead of panicking,
+    /// If the array doesn't have enough capacity.
+    ///
+    /// The array is unchanged on error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[inline]
+    fn try_insert_slice(&mut self, index: usize, other: &[T]) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        assert_insert_index(index, self.len());
+        self.try_ensure_capacity(self.len() + other.len())?;
+        self.insert_slice(index, other);
+        Ok(())
+    }
+
+    /// Inserts all the items of `iter` at position `index`, Shifting all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`, Or if the array doesn't have enough capacity,
+    /// The array is unchanged in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: ArrayBuf<_, 5> = ArrayBuf::from([1, 5].as_slice());
+    /// arr.insert_from_iter(1, 2..5);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    fn insert_from_iter<I>(&mut self, index: usize, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        if let Err(err) = self.try_insert_from_iter(index, iter) {
+            panic!("{err}");
+        }
+    }
+
+    /// Like [`insert_from_iter`](Array::insert_from_iter), But returns an error instead of panicking,
+    /// If the array doesn't have enough capacity.
+    ///
+    /// The array is unchanged on error, Or if `iter` panics, But the consumed items of `iter` are dropped.
+    /// `iter` isn't consumed past the first item that doesn't fit, So it may be unbounded.
+    /// The [`required`](CapacityError::required) length of the error is only a lower bound,
+    /// Based on the [`size_hint`](Iterator::size_hint) of the remaining items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    fn try_insert_from_iter<I>(&mut self, index: usize, iter: I) -> Result<(), CapacityError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let len = self.len();
+        assert_insert_index(index, len);
+        let mut iter = iter.into_iter();
+        let guard = TruncateOnDrop::new(self, len);
+        while let Some(value) = iter.next() {
+            if let Err(err) = guard.arr.try_ensure_capacity(guard.arr.len() + 1) {
+                let required = (guard.arr.len() + 1).saturating_add(iter.size_hint().0);
+                return Err(CapacityError::new(required, err.capacity()));
+            }
+            // SAFETY: Capacity is checked, Each element is counted as soon as it is written.
+            unsafe { push_unchecked(guard.arr, value) };
+        }
+        mem::forget(guard);
+        let count = self.len() - len;
+        self.as_mut_slice()[index..].rotate_right(count);
+        Ok(())
+    }
+
+    /// Appends elements returned by `f` until the array is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: ArrayBuf<_, 4> = ArrayBuf::from([1].as_slice());
+    /// arr.fill_to_capacity(|| 0);
+    /// assert_eq!(arr, [1, 0, 0, 0]);
+    /// ```
+    fn fill_to_capacity<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        for _ in self.len()..self.capacity() {
+            // SAFETY: `len < capacity`, Each element is counted as soon as it is written.
+            unsafe { push_unchecked(self, f()) };
+        }
+    }
+
+    /// Inserts `value` into a sorted array, Keeping it sorted. Returns the index of `value`.
+    ///
+    /// `value` is inserted after any equal elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: ArrayBuf<_, 4> = ArrayBuf::from([1, 3, 5].as_slice());
+    /// assert_eq!(arr.insert_sorted(4), 2);
+    /// assert_eq!(arr, [1, 3, 4, 5]);
+    /// ```
+    #[inline]
+    fn insert_sorted(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        let index = self.as_slice().partition_point(|probe| probe <= &value);
+        self.insert(index, value);
+        index
+    }
+
+    /// Inserts `value` into an array sorted by `f`, Keeping it sorted. Returns the index of `value`.
+    ///
+    /// `value` is inserted after any elements with an equal key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: ArrayBuf<_, 4> = ArrayBuf::from([(1, 'a'), (3, 'b')].as_slice());
+    /// arr.insert_sorted_by_key((1, 'c'), |&(n, _)| n);
+    /// assert_eq!(arr[..], [(1, 'a'), (1, 'c'), (3, 'b')]);
+    /// ```
+    #[inline]
+    fn insert_sorted_by_key<K, F>(&mut self, value: T, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        let key = f(&value);
+        let index = self.as_slice().partition_point(|probe| f(probe) <= key);
+        self.insert(index, value);
+        index
+    }
+
+    /// Removes an element equal to `value` from a sorted array and returns it, Or `None` if not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr = ArrayBuf::from([1, 3, 5]);
+    /// assert_eq!(arr.remove_sorted(&3), Some(3));
+    /// assert_eq!(arr.remove_sorted(&4), None);
+    /// assert_eq!(arr[..], [1, 5]);
+    /// ```
+    #[inline]
+    fn remove_sorted(&mut self, value: &T) -> Option<T>
+    where
+        T: Ord,
+    {
+        let index = self.as_slice().binary_search(value).ok()?;
+        Some(self.remove(index))
+    }
+
+    /// Returns `true`, If a sorted array contains an element equal to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let arr = ArrayBuf::from([1, 3, 5]);
+    /// assert!(arr.contains_sorted(&3));
+    /// assert!(!arr.contains_sorted(&4));
+    /// ```
+    #[inline]
+    fn contains_sorted(&self, value: &T) -> bool
+    where
+        T: Ord,
+    {
+        self.as_slice().binary_search(value).is_ok()
+    }
+
+    /// Moves all the elements of a sorted `other` into a sorted array, Keeping it sorted, Leaving `other` empty.
+    ///
+    /// The merge is stable, Equal elements from `self` come before those from `other`.
+    /// It's done in place in one pass from the back, Using the spare capacity as scratch.
+    ///
+    /// If the comparison panics, All the elements are still moved, But the array may not be sorted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array doesn't have enough capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: ArrayBuf<_, 6> = ArrayBuf::from([1, 4, 6].as_slice());
+    /// let mut other = ArrayBuf::from([2, 4, 7]);
+    /// arr.merge_sorted(&mut other);
+    ///
+    /// assert_eq!(arr, [1, 2, 4, 4, 6, 7]);
+    /// assert!(other.is_empty());
+    /// ```
+    fn merge_sorted(&mut self, other: &mut impl Array<T>)
+    where
+        T: Ord,
+    {
+        /// Moves the rest of `src` into the gap, And sets the final length, Even if `Ord` panics.
+        struct MergeOnDrop<'a, T, A: Array<T>> {
+            arr: &'a mut A,
+            src: *const T,
+            /// Number of elements of `arr`, That are not moved yet.
+            left: usize,
+            /// Number of elements of `src`, That are not moved yet.
+            right: usize,
+            total_len: usize,
+        }
+
+        impl<T, A: Array<T>> Drop for MergeOnDrop<'_, T, A> {
+            fn drop(&mut self) {
+                unsafe {
+                    // SAFETY: The gap `left..left + right` is exactly the size of the remaining `src`.
+                    let dst = self.arr.as_mut_ptr().add(self.left);
+                    ptr::copy_nonoverlapping(self.src, dst, self.right);
+                    self.arr.set_len(self.total_len);
+                }
+            }
+        }
+
+        let (len, count) = (self.len(), other.len());
+        self.ensure_capacity(len + count);
+        unsafe {
+            other.set_len(0);
+            self.set_len(0);
+            let mut merge = MergeOnDrop {
+                arr: self,
+                src: other.as_ptr(),
+                left: len,
+                right: count,
+                total_len: len + count,
+            };
+            while merge.left > 0 && merge.right > 0 {
+                let dst = merge.arr.as_mut_ptr();
+                let a = dst.add(merge.left - 1);
+                let b = merge.src.add(merge.right - 1);
+                let out = dst.add(merge.left + merge.right - 1);
+                if *b < *a {
+                    ptr::copy_nonoverlapping(a, out, 1);
+                    merge.left -= 1;
+                } else {
+                    ptr::copy_nonoverlapping(b, out, 1);
+                    merge.right -= 1;
+                }
+            }
+        }
+    }
+
+    /// Removes every element that occurs more than once in a sorted array, In one pass.
+    ///
+    /// Unlike [`dedup`](Array::dedup) which keeps the first element of each run of equal elements,
+    /// This keeps only the elements that are unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr = ArrayBuf::from([1, 2, 2, 3, 4, 4, 4, 5]);
+    /// arr.dedup_sorted_all();
+    /// assert_eq!(arr[..], [1, 3, 5]);
+    /// ```
+    fn dedup_sorted_all(&mut self)
+    where
+        T: PartialEq,
+    {
+        let slice = self.as_mut_slice();
+        let mut write = 0;
+        let mut start = 0;
+        while start < slice.len() {
+            let mut end = start + 1;
+            while end < slice.len() && slice[end] == slice[start] {
+                end += 1;
+            }
+            if end - start == 1 {
+                slice.swap(write, start);
+                write += 1;
+            }
+            start = end;
+        }
+        self.truncate(write);
+    }
+}
+
+/// Truncates the array back to `len` on drop, Unless it is forgotten.
+///
+/// Drops the elements appended so far, If a clone or an iterator panics before they are moved into place.
+struct TruncateOnDrop<'a, T, A: Array<T>> {
+    arr: &'a mut A,
+    len: usize,
+    _marker: core::marker::PhantomData<T>,
+}
+
+impl<'a, T, A: Array<T>> TruncateOnDrop<'a, T, A> {
+    #[inline]
+    fn new(arr: &'a mut A, len: usize) -> Self {
+        Self {
+            arr,
+            len,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, A: Array<T>> Drop for TruncateOnDrop<'_, T, A> {
+    fn drop(&mut self) {
+        self.arr.truncate(self.len);
+    }
+}
+
+/// Appends `value` without checking the capacity.
+///
+/// # Safety
+///
+/// `arr.len()` must be less than `arr.capacity()`.
+#[inline]
+unsafe fn push_unchecked<T>(arr: &mut impl Array<T>, value: T) {
+    let len = arr.len();
+    ptr::write(arr.as_mut_ptr().add(len), value);
+    arr.set_len(len + 1);
+}
+
+#[inline]
+fn assert_insert_index(index: usize, len: usize) {
+    #[cold]
+    #[inline(never)]
+    fn assert_failed(index: usize, len: usize) -> ! {
+        panic!("insertion index (is {index}) should be <= len (is {len})");
+    }
+    if index > len {
+        assert_failed(index, len);
+    }
 }
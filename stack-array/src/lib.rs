@@ -1,19 +1,80 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "allocator-api2")]
+mod arena;
+#[cfg(feature = "rkyv")]
+mod archive;
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+mod async_io;
+#[cfg(feature = "std")]
+mod buf_io;
+#[cfg(feature = "bytes")]
+mod bytes_buf;
+#[cfg(any(feature = "std", feature = "embedded-io", feature = "embedded-io-async"))]
+mod byte_io;
+#[cfg(any(feature = "differential", feature = "arbitrary", feature = "proptest"))]
+pub mod differential;
 mod drain;
+#[cfg(any(feature = "embedded-io", feature = "embedded-io-async"))]
+mod embedded;
+mod error;
+#[cfg(feature = "defmt")]
+mod format;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+#[cfg(feature = "std")]
+mod heap;
+mod history;
 mod interface;
+mod list;
+mod matrix;
+#[cfg(feature = "rayon")]
+mod par_iter;
 mod partial_eq;
+#[cfg(feature = "bytemuck")]
+mod pod;
 mod retain_mut;
+#[cfg(feature = "zeroize")]
+mod secret;
+mod sparse_set;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+#[cfg(feature = "ufmt")]
+mod uformat;
+mod union_find;
+#[cfg(any(feature = "std", feature = "allocator-api2"))]
 mod vector;
+#[cfg(feature = "std")]
 mod write;
 
+#[cfg(feature = "allocator-api2")]
+pub use arena::StackArena;
+#[cfg(feature = "rkyv")]
+pub use archive::{ArchivedArrayBuf, ArchivedLengthError};
+#[cfg(feature = "bytes")]
+pub use bytes_buf::BytesReader;
+#[cfg(feature = "std")]
+pub use buf_io::{IntoInnerError, StackBufReader, StackBufWriter};
 pub use drain::Drain;
+pub use error::CapacityError;
+#[cfg(feature = "std")]
+pub use heap::HeapArrayBuf;
+pub use history::{HistoryBuf, OldestOrdered};
 pub use interface::Array;
-use retain_mut::retain_mut;
+pub use list::{ArrayList, CursorMut, ListIntoIter, ListIter, ListIterMut};
+pub use matrix::{ArrayMatrix, Column, ColumnMut};
+#[cfg(feature = "rayon")]
+pub use par_iter::{IntoParIter, ParDrain};
+#[cfg(feature = "zeroize")]
+pub use secret::{SecretArrayBuf, SecretDrain};
+pub use sparse_set::SparseSet;
+pub use union_find::{Components, UnionFind};
+use retain_mut::{retain_mut, retain_with};
 
 use core::{
     borrow::*,
-    fmt, hash, mem,
+    fmt, hash, iter, mem,
     mem::MaybeUninit,
     ops,
     ops::{Deref, DerefMut, Index, IndexMut, Range, RangeBounds},
@@ -22,9 +83,18 @@ use core::{
     slice,
     slice::SliceIndex,
 };
-use std::cmp;
+use core::cmp;
 
 /// A data structure for storing and manipulating fixed number of elements of a specific type.
+///
+/// The layout is guaranteed to be `#[repr(C)]`: The length as `usize`, Followed by `N` slots of `T`.
+/// Only the first `len` slots are initialized.
+///
+/// The capacity is exactly `N`, Even if `T` is a zero-sized type (Unlike `Vec<T>`, Whose capacity is `usize::MAX`).
+/// So `ArrayBuf<(), 3>` hold at most 3 elements, And `ArrayBuf<T, 0>` is always empty and full:
+/// Every method that would add an element panics, Like any other full array.
+/// Elements of a zero-sized type are still dropped exactly once, Like `Vec<T>`.
+#[repr(C)]
 pub struct ArrayBuf<T, const N: usize> {
     len: usize,
     buf: [MaybeUninit<T>; N],
@@ -48,6 +118,132 @@ impl<T, const N: usize> ArrayBuf<T, N> {
         }
     }
 
+    /// Constructs a new, `ArrayBuf`, And fail to compile if it is larger than `MAX_BYTES`.
+    ///
+    /// Useful as a guard against stack overflow, When `T` or `N` are generic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let arr: ArrayBuf<u8, 64> = ArrayBuf::new_checked::<1024>();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use stack_array::*;
+    ///
+    /// let arr: ArrayBuf<u64, 1024> = ArrayBuf::new_checked::<1024>();
+    /// ```
+    #[inline]
+    pub const fn new_checked<const MAX_BYTES: usize>() -> Self {
+        const {
+            assert!(
+                mem::size_of::<Self>() <= MAX_BYTES,
+                "`ArrayBuf` is larger than `MAX_BYTES`"
+            )
+        };
+        Self::new()
+    }
+
+    /// Initializes an empty `ArrayBuf` in place, Without constructing it on the stack.
+    ///
+    /// Only the length is written, The slots are left uninitialized.
+    ///
+    /// `slot` is still a `MaybeUninit`, That never drops its content. So the elements are leaked,
+    /// Unless the caller drops the array with [`MaybeUninit::assume_init_drop`],
+    /// Or takes it out with [`MaybeUninit::assume_init`], Once it is no longer borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut slot = MaybeUninit::uninit();
+    /// let arr: &mut ArrayBuf<String, 4096> = ArrayBuf::new_in(&mut slot);
+    /// arr.push("Hello".to_string());
+    /// assert_eq!(arr.len(), 1);
+    ///
+    /// // SAFETY: `new_in` initialized `slot`, And it isn't used afterward.
+    /// unsafe { slot.assume_init_drop() };
+    /// ```
+    #[inline]
+    pub fn new_in(slot: &mut MaybeUninit<Self>) -> &mut Self {
+        unsafe {
+            // SAFETY: `buf` is an array of `MaybeUninit`, Only `len` needs to be initialized.
+            ptr::addr_of_mut!((*slot.as_mut_ptr()).len).write(0);
+            slot.assume_init_mut()
+        }
+    }
+
+    /// Constructs a new, Empty `ArrayBuf` directly on the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let arr: Box<ArrayBuf<u64, 1_000_000>> = ArrayBuf::new_boxed();
+    /// assert!(arr.is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn new_boxed() -> Box<Self> {
+        Self::new_boxed_with(|_| {})
+    }
+
+    /// Constructs a new `ArrayBuf` directly on the heap, Then initialize it with `init`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let arr: Box<ArrayBuf<u64, 1_000_000>> = ArrayBuf::new_boxed_with(|arr| {
+    ///     for i in 0..10 {
+    ///         arr.push(i);
+    ///     }
+    /// });
+    /// assert_eq!(arr.len(), 10);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn new_boxed_with(init: impl FnOnce(&mut Self)) -> Box<Self> {
+        let mut boxed = Box::new_uninit();
+        init(Self::new_in(&mut boxed));
+        // SAFETY: `new_in` initialized the array.
+        unsafe { boxed.assume_init() }
+    }
+
+    /// Clones the elements of `values` into a new array, Or returns `values` back if it has more than `N` elements.
+    ///
+    /// This is the non-panicking counterpart of `From<&[T]>`. (A `TryFrom<&[T]>` impl would conflict
+    /// with the blanket `TryFrom` impl that `From<&[T]>` already provides.)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let arr = ArrayBuf::<_, 4>::try_from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!(arr, [1, 2, 3]);
+    ///
+    /// assert_eq!(ArrayBuf::<_, 2>::try_from_slice(&[1, 2, 3]), Err(&[1, 2, 3][..]));
+    /// ```
+    pub fn try_from_slice(values: &[T]) -> Result<Self, &[T]>
+    where
+        T: Clone,
+    {
+        if values.len() > N {
+            return Err(values);
+        }
+        let mut arr = Self::new();
+        for value in values {
+            arr.push(value.clone());
+        }
+        Ok(arr)
+    }
+
     /// Returns `true`, If the array is full.
     ///
     /// # Examples
@@ -62,9 +258,126 @@ impl<T, const N: usize> ArrayBuf<T, N> {
     pub const fn is_full(&self) -> bool {
         self.len >= N
     }
+
+    /// Splits the array into two at the given index.
+    ///
+    /// Returns a newly allocated array containing the elements in the range `[at, len)`.
+    /// After the call, The original array will be left containing the elements `[0, at)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`, Or the new array can't hold `len - at` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr = ArrayBuf::from([1, 2, 3, 4]);
+    /// let tail: ArrayBuf<_, 2> = arr.split_off(2);
+    ///
+    /// assert_eq!(arr[..], [1, 2]);
+    /// assert_eq!(tail, [3, 4]);
+    /// ```
+    pub fn split_off<const M: usize>(&mut self, at: usize) -> ArrayBuf<T, M> {
+        #[cold]
+        #[inline(never)]
+        fn assert_failed(at: usize, len: usize) -> ! {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+        if at > self.len {
+            assert_failed(at, self.len);
+        }
+        let mut other = ArrayBuf::new();
+        other.ensure_capacity(self.len - at);
+        unsafe {
+            // SAFETY: `other` is empty, And has the capacity for `len - at` elements.
+            let count = self.len - at;
+            self.set_len(at);
+            ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), count);
+            other.set_len(count);
+        }
+        other
+    }
+
+    /// Moves all the elements of `self` and `other` into a new array with capacity `O`.
+    ///
+    /// `O` is checked at compile-time, It must be at least `N + M`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let arr: ArrayBuf<_, 5> = ArrayBuf::from([1, 2]).concat(ArrayBuf::from([3, 4, 5]));
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use stack_array::*;
+    ///
+    /// let arr: ArrayBuf<_, 4> = ArrayBuf::from([1, 2]).concat(ArrayBuf::from([3, 4, 5]));
+    /// ```
+    pub fn concat<const M: usize, const O: usize>(
+        mut self,
+        mut other: ArrayBuf<T, M>,
+    ) -> ArrayBuf<T, O> {
+        const {
+            assert!(
+                N + M <= O,
+                "`ArrayBuf` can't hold the elements of both arrays"
+            )
+        };
+        let mut arr = ArrayBuf::new();
+        unsafe {
+            // SAFETY: `arr` can hold `N + M` elements, And ownership of the elements is moved.
+            let (len, other_len) = (self.len, other.len);
+            self.set_len(0);
+            other.set_len(0);
+            ptr::copy_nonoverlapping(self.as_ptr(), arr.as_mut_ptr(), len);
+            ptr::copy_nonoverlapping(other.as_ptr(), arr.as_mut_ptr().add(len), other_len);
+            arr.set_len(len + other_len);
+        }
+        arr
+    }
+
+    /// Moves the elements into an array with capacity `M`, Without cloning.
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` unchanged, If it has more than `M` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut arr: ArrayBuf<_, 8> = ArrayBuf::new();
+    /// arr.extend_from_slice([1, 2, 3]);
+    ///
+    /// let arr = arr.resize_capacity::<2>().unwrap_err();
+    /// let arr: ArrayBuf<_, 3> = arr.resize_capacity().unwrap();
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    pub fn resize_capacity<const M: usize>(mut self) -> Result<ArrayBuf<T, M>, Self> {
+        if self.len > M {
+            return Err(self);
+        }
+        let mut arr = ArrayBuf::new();
+        unsafe {
+            // SAFETY: `len <= M`, And ownership of the elements is moved.
+            let len = self.len;
+            self.set_len(0);
+            ptr::copy_nonoverlapping(self.as_ptr(), arr.as_mut_ptr(), len);
+            arr.set_len(len);
+        }
+        Ok(arr)
+    }
 }
 
-impl<T, const N: usize> Array<T> for ArrayBuf<T, N> {
+// SAFETY: `buf` is an inline array of `N` slots, That never move while borrowed,
+// And the first `len <= N` slots are initialized.
+unsafe impl<T, const N: usize> Array<T> for ArrayBuf<T, N> {
     #[inline]
     fn capacity(&self) -> usize {
         N
@@ -150,6 +463,89 @@ impl<T: Copy, const N: usize> From<[T; N]> for ArrayBuf<T, N> {
     }
 }
 
+#[cfg(feature = "std")]
+macro_rules! impl_vec_conversions {
+    ($($ty:ident),*) => {$(
+        /// Moves the elements into a new `Vec`, That allocates exactly `len` slots.
+        impl<T, const N: usize> From<$ty<T, N>> for Vec<T> {
+            fn from(mut arr: $ty<T, N>) -> Self {
+                let len = arr.len();
+                let mut vec = Vec::with_capacity(len);
+                // SAFETY: `vec` has room for `len` elements, And `arr` forgets them before it is dropped.
+                unsafe {
+                    ptr::copy_nonoverlapping(arr.as_ptr(), vec.as_mut_ptr(), len);
+                    arr.set_len(0);
+                    vec.set_len(len);
+                }
+                vec
+            }
+        }
+
+        impl<T, const N: usize> From<$ty<T, N>> for Box<[T]> {
+            #[inline]
+            fn from(arr: $ty<T, N>) -> Self {
+                Vec::from(arr).into_boxed_slice()
+            }
+        }
+
+        impl<T, const N: usize> From<$ty<T, N>> for std::rc::Rc<[T]> {
+            #[inline]
+            fn from(arr: $ty<T, N>) -> Self {
+                Vec::from(arr).into()
+            }
+        }
+
+        impl<T, const N: usize> From<$ty<T, N>> for std::sync::Arc<[T]> {
+            #[inline]
+            fn from(arr: $ty<T, N>) -> Self {
+                Vec::from(arr).into()
+            }
+        }
+
+        impl<T, const N: usize> From<$ty<T, N>> for std::collections::VecDeque<T> {
+            #[inline]
+            fn from(arr: $ty<T, N>) -> Self {
+                Vec::from(arr).into()
+            }
+        }
+
+        /// Moves the elements out of the `Vec`, Or returns it back if it has more than `N` elements.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use stack_array::*;
+        ///
+        #[doc = concat!("let arr: ", stringify!($ty), "<_, 4> = ", stringify!($ty), "::try_from(vec![1, 2, 3]).unwrap();")]
+        /// assert_eq!(arr, [1, 2, 3]);
+        ///
+        #[doc = concat!("let err = ", stringify!($ty), "::<_, 2>::try_from(vec![1, 2, 3]).unwrap_err();")]
+        /// assert_eq!(err, [1, 2, 3]);
+        /// ```
+        impl<T, const N: usize> TryFrom<Vec<T>> for $ty<T, N> {
+            type Error = Vec<T>;
+
+            fn try_from(mut vec: Vec<T>) -> Result<Self, Self::Error> {
+                let len = vec.len();
+                if len > N {
+                    return Err(vec);
+                }
+                let mut arr = Self::new();
+                // SAFETY: `len <= N`, And `vec` forgets the elements before it is dropped.
+                unsafe {
+                    ptr::copy_nonoverlapping(vec.as_ptr(), arr.as_mut_ptr(), len);
+                    vec.set_len(0);
+                    arr.set_len(len);
+                }
+                Ok(arr)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "std")]
+impl_vec_conversions!(ArrayBuf, HeapArrayBuf);
+
 impl<T, I: SliceIndex<[T]>, const N: usize> Index<I> for ArrayBuf<T, N> {
     type Output = I::Output;
     #[inline]
@@ -183,14 +579,6 @@ impl<T, const N: usize> BorrowMut<[T]> for ArrayBuf<T, N> {
     }
 }
 
-/// Implements comparison of vectors, [lexicographically](core::cmp::Ord#lexicographical-comparison).
-impl<T: PartialOrd, const N: usize> cmp::PartialOrd for ArrayBuf<T, N> {
-    #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        PartialOrd::partial_cmp(&**self, &**other)
-    }
-}
-
 impl<T: Eq, const N: usize> Eq for ArrayBuf<T, N> {}
 
 /// Implements ordering of vectors, [lexicographically](core::cmp::Ord#lexicographical-comparison).
@@ -0,0 +1,680 @@
+//! An index-linked list with inline storage, See [`ArrayList`].
+
+use crate::*;
+use core::marker::PhantomData;
+
+const NIL: usize = usize::MAX;
+
+#[derive(Clone, Copy)]
+struct Link {
+    prev: usize,
+    next: usize,
+}
+
+/// A doubly linked list, Stored inline with `N` slots.
+///
+/// The nodes are linked by index into fixed storage, So inserting or removing at a [`CursorMut`]
+/// is `O(1)` and never allocates, Unlike [`Array::insert`] and [`Array::remove`] that shift the
+/// elements after the index. Freed slots are reused by later insertions.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+///
+/// let mut list: ArrayList<u8, 4> = ArrayList::new();
+/// list.push_back(2);
+/// list.push_back(4);
+/// list.push_front(1);
+///
+/// let mut cursor = list.cursor_front_mut();
+/// cursor.move_next();
+/// cursor.insert_after(3);
+///
+/// assert!(list.iter().eq(&[1, 2, 3, 4]));
+/// ```
+pub struct ArrayList<T, const N: usize> {
+    head: usize,
+    tail: usize,
+    len: usize,
+    /// Number of slots that were ever allocated, The slots after it are untouched.
+    used: usize,
+    /// Head of the list of freed slots, Linked by `next`.
+    free: usize,
+    links: [Link; N],
+    values: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> ArrayList<T, N> {
+    /// Constructs a new, Empty `ArrayList`
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            head: NIL,
+            tail: NIL,
+            len: 0,
+            used: 0,
+            free: NIL,
+            links: [Link {
+                prev: NIL,
+                next: NIL,
+            }; N],
+            values: unsafe { MaybeUninit::uninit().assume_init() },
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of elements the list can hold, That is `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true`, If the list contains no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true`, If the list is full.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len >= N
+    }
+
+    /// Returns a reference to the first element, Or `None` if the list is empty.
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(self.head)
+    }
+
+    /// Returns a mutable reference to the first element, Or `None` if the list is empty.
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.head)
+    }
+
+    /// Returns a reference to the last element, Or `None` if the list is empty.
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.tail)
+    }
+
+    /// Returns a mutable reference to the last element, Or `None` if the list is empty.
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.tail)
+    }
+
+    /// Prepends an element to the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    #[inline]
+    pub fn push_front(&mut self, value: T) {
+        let node = self.alloc(value);
+        self.link(node, NIL, self.head);
+    }
+
+    /// Appends an element to the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    #[inline]
+    pub fn push_back(&mut self, value: T) {
+        let node = self.alloc(value);
+        self.link(node, self.tail, NIL);
+    }
+
+    /// Removes the first element and returns it, Or `None` if the list is empty.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        (self.head != NIL).then(|| self.unlink(self.head))
+    }
+
+    /// Removes the last element and returns it, Or `None` if the list is empty.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        (self.tail != NIL).then(|| self.unlink(self.tail))
+    }
+
+    /// Removes all the elements, Dropping them.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+        self.used = 0;
+        self.free = NIL;
+    }
+
+    /// Returns an iterator over the elements, From the front to the back.
+    #[inline]
+    pub fn iter(&self) -> ListIter<'_, T> {
+        ListIter {
+            links: &self.links,
+            values: &self.values,
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element, From the front to the back.
+    #[inline]
+    pub fn iter_mut(&mut self) -> ListIterMut<'_, T> {
+        ListIterMut {
+            links: &self.links,
+            values: self.values.as_mut_ptr() as *mut T,
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a cursor pointing at the first element.
+    ///
+    /// If the list is empty, The cursor points at the "ghost" element.
+    #[inline]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, N> {
+        CursorMut {
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// Returns a cursor pointing at the last element.
+    ///
+    /// If the list is empty, The cursor points at the "ghost" element.
+    #[inline]
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, N> {
+        CursorMut {
+            current: self.tail,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    #[inline]
+    fn get(&self, node: usize) -> Option<&T> {
+        (node != NIL).then(|| unsafe { self.values[node].assume_init_ref() })
+    }
+
+    #[inline]
+    fn get_mut(&mut self, node: usize) -> Option<&mut T> {
+        (node != NIL).then(|| unsafe { self.values[node].assume_init_mut() })
+    }
+
+    /// Stores `value` in a free slot, Without linking it.
+    fn alloc(&mut self, value: T) -> usize {
+        if self.is_full() {
+            panic!("List is full, Max capacity: {N}");
+        }
+        let node = if self.free != NIL {
+            let node = self.free;
+            self.free = self.links[node].next;
+            node
+        } else {
+            self.used += 1;
+            self.used - 1
+        };
+        self.values[node].write(value);
+        self.len += 1;
+        node
+    }
+
+    /// Links `node` between `prev` and `next`, Either of them can be `NIL`.
+    fn link(&mut self, node: usize, prev: usize, next: usize) {
+        self.links[node] = Link { prev, next };
+        match prev {
+            NIL => self.head = node,
+            _ => self.links[prev].next = node,
+        }
+        match next {
+            NIL => self.tail = node,
+            _ => self.links[next].prev = node,
+        }
+    }
+
+    /// Unlinks `node` and frees its slot, Returning the element.
+    fn unlink(&mut self, node: usize) -> T {
+        let Link { prev, next } = self.links[node];
+        match prev {
+            NIL => self.head = next,
+            _ => self.links[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            _ => self.links[next].prev = prev,
+        }
+        self.links[node].next = self.free;
+        self.free = node;
+        self.len -= 1;
+        unsafe { self.values[node].assume_init_read() }
+    }
+}
+
+/// A cursor over an [`ArrayList`], That can insert and remove elements in `O(1)`.
+///
+/// The cursor points either at an element, Or at a "ghost" element between the back and the front
+/// of the list. Moving past either end of the list moves to the ghost, And moving from the ghost
+/// wraps around to the other end.
+///
+/// This struct is created by [`ArrayList::cursor_front_mut`] and [`ArrayList::cursor_back_mut`].
+pub struct CursorMut<'a, T, const N: usize> {
+    list: &'a mut ArrayList<T, N>,
+    current: usize,
+    index: usize,
+}
+
+impl<T, const N: usize> CursorMut<'_, T, N> {
+    /// Returns the index of the current element, Or `None` if the cursor points at the ghost.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        (self.current != NIL).then_some(self.index)
+    }
+
+    /// Returns a mutable reference to the current element, Or `None` if the cursor points at the ghost.
+    #[inline]
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.list.get_mut(self.current)
+    }
+
+    /// Returns a mutable reference to the next element, Without moving the cursor.
+    ///
+    /// If the cursor points at the ghost, This is the first element.
+    #[inline]
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = self.next_node();
+        self.list.get_mut(next)
+    }
+
+    /// Returns a mutable reference to the previous element, Without moving the cursor.
+    ///
+    /// If the cursor points at the ghost, This is the last element.
+    #[inline]
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = self.prev_node();
+        self.list.get_mut(prev)
+    }
+
+    /// Moves the cursor to the next element.
+    ///
+    /// If the cursor points at the last element, It moves to the ghost.
+    #[inline]
+    pub fn move_next(&mut self) {
+        self.index = match self.current {
+            NIL => 0,
+            _ => self.index + 1,
+        };
+        self.current = self.next_node();
+    }
+
+    /// Moves the cursor to the previous element.
+    ///
+    /// If the cursor points at the first element, It moves to the ghost.
+    #[inline]
+    pub fn move_prev(&mut self) {
+        self.current = self.prev_node();
+        self.index = match self.current {
+            NIL => self.list.len,
+            _ => self.index - 1,
+        };
+    }
+
+    /// Inserts an element after the current one, Without moving the cursor.
+    ///
+    /// If the cursor points at the ghost, The element is inserted at the front of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    pub fn insert_after(&mut self, value: T) {
+        let node = self.list.alloc(value);
+        let next = self.next_node();
+        self.list.link(node, self.current, next);
+        if self.current == NIL {
+            self.index = self.list.len;
+        }
+    }
+
+    /// Inserts an element before the current one, Without moving the cursor.
+    ///
+    /// If the cursor points at the ghost, The element is inserted at the back of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    pub fn insert_before(&mut self, value: T) {
+        let node = self.list.alloc(value);
+        let prev = self.prev_node();
+        self.list.link(node, prev, self.current);
+        self.index += 1;
+    }
+
+    /// Removes the current element and returns it, Moving the cursor to the next element.
+    ///
+    /// Returns `None`, If the cursor points at the ghost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut list: ArrayList<_, 4> = ArrayList::from([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// assert_eq!(cursor.current(), Some(&mut 3));
+    /// assert!(list.iter().eq(&[1, 3, 4]));
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.current == NIL {
+            return None;
+        }
+        let next = self.next_node();
+        let value = self.list.unlink(self.current);
+        self.current = next;
+        if next == NIL {
+            self.index = self.list.len;
+        }
+        Some(value)
+    }
+
+    /// Moves all the elements of `other` after the current one, Without moving the cursor.
+    ///
+    /// If the cursor points at the ghost, The elements are inserted at the front of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements of `other` doesn't fit in the list. Nothing is moved in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut list: ArrayList<_, 5> = ArrayList::from_iter([1, 4]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.splice_after(ArrayList::<_, 2>::from([2, 3]));
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// assert!(list.iter().eq(&[1, 2, 3, 4]));
+    /// ```
+    pub fn splice_after<const M: usize>(&mut self, mut other: ArrayList<T, M>) {
+        self.assert_fits(other.len);
+        while let Some(value) = other.pop_back() {
+            self.insert_after(value);
+        }
+    }
+
+    /// Moves all the elements of `other` before the current one, Without moving the cursor.
+    ///
+    /// If the cursor points at the ghost, The elements are inserted at the back of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements of `other` doesn't fit in the list. Nothing is moved in that case.
+    pub fn splice_before<const M: usize>(&mut self, mut other: ArrayList<T, M>) {
+        self.assert_fits(other.len);
+        while let Some(value) = other.pop_front() {
+            self.insert_before(value);
+        }
+    }
+
+    #[inline]
+    fn next_node(&self) -> usize {
+        match self.current {
+            NIL => self.list.head,
+            node => self.list.links[node].next,
+        }
+    }
+
+    #[inline]
+    fn prev_node(&self) -> usize {
+        match self.current {
+            NIL => self.list.tail,
+            node => self.list.links[node].prev,
+        }
+    }
+
+    #[inline]
+    fn assert_fits(&self, additional: usize) {
+        let total_len = self.list.len + additional;
+        if total_len > N {
+            panic!("List is full, Max capacity: {N}, But got: {total_len}");
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayList<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for ArrayList<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ArrayList<T, N> {
+    fn from(values: [T; N]) -> Self {
+        Self::from_iter(values)
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ArrayList<T, N> {
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayList<T, N> {
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `N` elements.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayList<T, N> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayList<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<ArrayList<U, M>>
+    for ArrayList<T, N>
+{
+    fn eq(&self, other: &ArrayList<U, M>) -> bool {
+        self.len == other.len && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayList<T, N> {}
+
+impl<T: hash::Hash, const N: usize> hash::Hash for ArrayList<T, N> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        self.iter().for_each(|value| value.hash(state));
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayList<T, N> {
+    type Item = &'a T;
+    type IntoIter = ListIter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayList<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = ListIterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayList<T, N> {
+    type Item = T;
+    type IntoIter = ListIntoIter<T, N>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        ListIntoIter { list: self }
+    }
+}
+
+/// An iterator over the elements of an [`ArrayList`].
+///
+/// This struct is created by [`ArrayList::iter`].
+pub struct ListIter<'a, T> {
+    links: &'a [Link],
+    values: &'a [MaybeUninit<T>],
+    front: usize,
+    back: usize,
+    len: usize,
+}
+
+impl<T> Clone for ListIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for ListIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.front;
+        self.front = self.links[node].next;
+        self.len -= 1;
+        Some(unsafe { self.values[node].assume_init_ref() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ListIter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.back;
+        self.back = self.links[node].prev;
+        self.len -= 1;
+        Some(unsafe { self.values[node].assume_init_ref() })
+    }
+}
+
+impl<T> ExactSizeIterator for ListIter<'_, T> {}
+
+/// A mutable iterator over the elements of an [`ArrayList`].
+///
+/// This struct is created by [`ArrayList::iter_mut`].
+pub struct ListIterMut<'a, T> {
+    links: &'a [Link],
+    values: *mut T,
+    front: usize,
+    back: usize,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for ListIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.front;
+        self.front = self.links[node].next;
+        self.len -= 1;
+        // SAFETY: Every linked node is initialized, And is yielded at most once.
+        Some(unsafe { &mut *self.values.add(node) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ListIterMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.back;
+        self.back = self.links[node].prev;
+        self.len -= 1;
+        Some(unsafe { &mut *self.values.add(node) })
+    }
+}
+
+impl<T> ExactSizeIterator for ListIterMut<'_, T> {}
+
+/// An owning iterator over the elements of an [`ArrayList`].
+///
+/// This struct is created by the `into_iter` method on `ArrayList`.
+pub struct ListIntoIter<T, const N: usize> {
+    list: ArrayList<T, N>,
+}
+
+impl<T, const N: usize> Iterator for ListIntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ListIntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ListIntoIter<T, N> {}
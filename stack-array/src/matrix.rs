@@ -0,0 +1,401 @@
+use crate::*;
+
+/// A rectangular grid of `R` rows and `C` columns, Stored inline in row-major order.
+///
+/// Rows are filled from the top, So the matrix can be partially filled by [`push_row`](ArrayMatrix::push_row).
+/// Every filled row has exactly `C` elements, Unlike `ArrayBuf<ArrayBuf<T, C>, R>` which can be jagged.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+///
+/// let mut grid: ArrayMatrix<u8, 3, 2> = ArrayMatrix::new();
+/// grid.push_row([1, 2]);
+/// grid.push_row([3, 4]);
+///
+/// assert_eq!(grid[(1, 0)], 3);
+/// assert_eq!(grid.row(0), Some(&[1, 2]));
+/// assert!(grid.column(1).eq([&2, &4]));
+/// ```
+pub struct ArrayMatrix<T, const R: usize, const C: usize> {
+    rows: usize,
+    buf: [[MaybeUninit<T>; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> ArrayMatrix<T, R, C> {
+    /// Constructs a new, Empty `ArrayMatrix`
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            rows: 0,
+            buf: unsafe { MaybeUninit::uninit().assume_init() },
+        }
+    }
+
+    /// Constructs a full matrix, Where each element is `f(row, column)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let grid: ArrayMatrix<usize, 2, 3> = ArrayMatrix::from_fn(|r, c| r * 10 + c);
+    /// assert_eq!(grid.as_rows(), [[0, 1, 2], [10, 11, 12]]);
+    /// ```
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut matrix = Self::new();
+        for r in 0..R {
+            matrix.push_row(core::array::from_fn(|c| f(r, c)));
+        }
+        matrix
+    }
+
+    /// Returns the number of filled rows.
+    #[inline]
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns, That is `C`.
+    #[inline]
+    pub const fn columns(&self) -> usize {
+        C
+    }
+
+    /// Returns the number of rows the matrix can hold, That is `R`.
+    #[inline]
+    pub const fn row_capacity(&self) -> usize {
+        R
+    }
+
+    /// Returns `true`, If the matrix has no filled rows.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.rows == 0
+    }
+
+    /// Returns `true`, If all `R` rows are filled.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.rows >= R
+    }
+
+    /// Appends a row to the bottom of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is full.
+    #[inline]
+    pub fn push_row(&mut self, row: [T; C]) {
+        if self.is_full() {
+            panic!("Matrix is full, Max rows: {R}");
+        }
+        unsafe { ptr::write(self.buf.as_mut_ptr().add(self.rows) as *mut [T; C], row) };
+        self.rows += 1;
+    }
+
+    /// Removes the last row and returns it, Or `None` if the matrix is empty.
+    #[inline]
+    pub fn pop_row(&mut self) -> Option<[T; C]> {
+        if self.rows == 0 {
+            return None;
+        }
+        self.rows -= 1;
+        Some(unsafe { ptr::read(self.buf.as_ptr().add(self.rows) as *const [T; C]) })
+    }
+
+    /// Removes all the rows, Dropping the elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        let rows = ptr::slice_from_raw_parts_mut(self.buf.as_mut_ptr() as *mut [T; C], self.rows);
+        self.rows = 0;
+        unsafe { ptr::drop_in_place(rows) };
+    }
+
+    /// Returns the filled rows as a slice.
+    #[inline]
+    pub fn as_rows(&self) -> &[[T; C]] {
+        unsafe { slice::from_raw_parts(self.buf.as_ptr() as *const [T; C], self.rows) }
+    }
+
+    /// Returns the filled rows as a mutable slice.
+    #[inline]
+    pub fn as_rows_mut(&mut self) -> &mut [[T; C]] {
+        unsafe { slice::from_raw_parts_mut(self.buf.as_mut_ptr() as *mut [T; C], self.rows) }
+    }
+
+    /// Returns all the elements of the filled rows, In row-major order.
+    #[inline]
+    pub fn as_flat_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.buf.as_ptr() as *const T, self.rows * C) }
+    }
+
+    /// Returns all the elements of the filled rows, In row-major order, As a mutable slice.
+    #[inline]
+    pub fn as_flat_slice_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.buf.as_mut_ptr() as *mut T, self.rows * C) }
+    }
+
+    /// Returns a reference to the element at `(row, column)`, Or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.row(row)?.get(column)
+    }
+
+    /// Returns a mutable reference to the element at `(row, column)`, Or `None` if out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.row_mut(row)?.get_mut(column)
+    }
+
+    /// Returns the row at `index`, Or `None` if it isn't filled.
+    #[inline]
+    pub fn row(&self, index: usize) -> Option<&[T; C]> {
+        self.as_rows().get(index)
+    }
+
+    /// Returns the row at `index` mutably, Or `None` if it isn't filled.
+    #[inline]
+    pub fn row_mut(&mut self, index: usize) -> Option<&mut [T; C]> {
+        self.as_rows_mut().get_mut(index)
+    }
+
+    /// Returns an iterator over the column at `index`, From top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than or equal to `C`.
+    #[inline]
+    pub fn column(&self, index: usize) -> Column<'_, T, C> {
+        assert!(
+            index < C,
+            "column index {index} out of range for {C} columns"
+        );
+        Column {
+            rows: self.as_rows().iter(),
+            index,
+        }
+    }
+
+    /// Returns an iterator over the column at `index` mutably, From top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than or equal to `C`.
+    #[inline]
+    pub fn column_mut(&mut self, index: usize) -> ColumnMut<'_, T, C> {
+        assert!(
+            index < C,
+            "column index {index} out of range for {C} columns"
+        );
+        ColumnMut {
+            rows: self.as_rows_mut().iter_mut(),
+            index,
+        }
+    }
+
+    /// Returns a matrix of the same shape, With `f` applied to each element in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let grid = ArrayMatrix::from([[1, 2], [3, 4]]);
+    /// assert_eq!(grid.map(|v| v * 10).as_rows(), [[10, 20], [30, 40]]);
+    /// ```
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> ArrayMatrix<U, R, C> {
+        // Drops the rows that are not read yet, If `f` panics.
+        struct DropRemaining<'a, T, const R: usize, const C: usize> {
+            buf: &'a mut [[MaybeUninit<T>; C]; R],
+            next: usize,
+            rows: usize,
+        }
+        impl<T, const R: usize, const C: usize> Drop for DropRemaining<'_, T, R, C> {
+            fn drop(&mut self) {
+                let rows = self.buf.as_mut_ptr() as *mut [T; C];
+                unsafe {
+                    let remaining =
+                        ptr::slice_from_raw_parts_mut(rows.add(self.next), self.rows - self.next);
+                    ptr::drop_in_place(remaining);
+                }
+            }
+        }
+
+        let mut this = mem::ManuallyDrop::new(self);
+        let mut guard = DropRemaining {
+            rows: mem::replace(&mut this.rows, 0),
+            buf: &mut this.buf,
+            next: 0,
+        };
+        let mut matrix = ArrayMatrix::new();
+        while guard.next < guard.rows {
+            let row = unsafe { ptr::read(guard.buf.as_ptr().add(guard.next) as *const [T; C]) };
+            // The row is moved out, `[T; C]::map` drops the rest of it, If `f` panics.
+            guard.next += 1;
+            matrix.push_row(row.map(&mut f));
+        }
+        matrix
+    }
+}
+
+impl<T, const N: usize> ArrayMatrix<T, N, N> {
+    /// Transposes a square matrix in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let mut grid = ArrayMatrix::from([[1, 2], [3, 4]]);
+    /// grid.transpose();
+    /// assert_eq!(grid.as_rows(), [[1, 3], [2, 4]]);
+    /// ```
+    pub fn transpose(&mut self) {
+        assert!(self.is_full(), "can't transpose a partially filled matrix");
+        let flat = self.as_flat_slice_mut();
+        for r in 0..N {
+            for c in r + 1..N {
+                flat.swap(r * N + c, c * N + r);
+            }
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Drop for ArrayMatrix<T, R, C> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const R: usize, const C: usize> Default for ArrayMatrix<T, R, C> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for ArrayMatrix<T, R, C> {
+    fn from(rows: [[T; C]; R]) -> Self {
+        let mut matrix = Self::new();
+        for row in rows {
+            matrix.push_row(row);
+        }
+        matrix
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> Clone for ArrayMatrix<T, R, C> {
+    fn clone(&self) -> Self {
+        let mut matrix = Self::new();
+        for row in self.as_rows() {
+            matrix.push_row(row.clone());
+        }
+        matrix
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for ArrayMatrix<T, R, C> {
+    type Output = T;
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.as_rows()[row][column]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for ArrayMatrix<T, R, C> {
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self.as_rows_mut()[row][column]
+    }
+}
+
+impl<T: fmt::Debug, const R: usize, const C: usize> fmt::Debug for ArrayMatrix<T, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_rows()).finish()
+    }
+}
+
+impl<T: PartialEq<U>, U, const R: usize, const C: usize> PartialEq<ArrayMatrix<U, R, C>>
+    for ArrayMatrix<T, R, C>
+{
+    #[inline]
+    fn eq(&self, other: &ArrayMatrix<U, R, C>) -> bool {
+        self.as_flat_slice() == other.as_flat_slice()
+    }
+}
+
+impl<T: Eq, const R: usize, const C: usize> Eq for ArrayMatrix<T, R, C> {}
+
+impl<T: hash::Hash, const R: usize, const C: usize> hash::Hash for ArrayMatrix<T, R, C> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        hash::Hash::hash(self.as_rows(), state)
+    }
+}
+
+/// An iterator over a column of an [`ArrayMatrix`], Created by [`ArrayMatrix::column`].
+#[derive(Debug, Clone)]
+pub struct Column<'a, T, const C: usize> {
+    rows: slice::Iter<'a, [T; C]>,
+    index: usize,
+}
+
+impl<'a, T, const C: usize> Iterator for Column<'a, T, C> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next().map(|row| &row[self.index])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for Column<'_, T, C> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.rows.next_back().map(|row| &row[self.index])
+    }
+}
+
+impl<T, const C: usize> ExactSizeIterator for Column<'_, T, C> {}
+
+/// A mutable iterator over a column of an [`ArrayMatrix`], Created by [`ArrayMatrix::column_mut`].
+#[derive(Debug)]
+pub struct ColumnMut<'a, T, const C: usize> {
+    rows: slice::IterMut<'a, [T; C]>,
+    index: usize,
+}
+
+impl<'a, T, const C: usize> Iterator for ColumnMut<'a, T, C> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        self.rows.next().map(|row| &mut row[index])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for ColumnMut<'_, T, C> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        self.rows.next_back().map(|row| &mut row[index])
+    }
+}
+
+impl<T, const C: usize> ExactSizeIterator for ColumnMut<'_, T, C> {}
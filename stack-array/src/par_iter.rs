@@ -0,0 +1,517 @@
+use crate::{drain::slice_range, *};
+use core::marker::PhantomData;
+use rayon::iter::{
+    plumbing::{bridge, Consumer, Folder, Producer, ProducerCallback, Reducer, UnindexedConsumer},
+    IndexedParallelIterator, IntoParallelIterator, ParallelDrainRange, ParallelExtend,
+    ParallelIterator,
+};
+
+macro_rules! impl_par_borrowed {
+    ($($ty:ident),*) => {$(
+        impl<'a, T: Sync + 'a, const N: usize> IntoParallelIterator for &'a $ty<T, N> {
+            type Item = &'a T;
+            type Iter = rayon::slice::Iter<'a, T>;
+
+            fn into_par_iter(self) -> Self::Iter {
+                <&[T]>::into_par_iter(self)
+            }
+        }
+
+        impl<'a, T: Send + 'a, const N: usize> IntoParallelIterator for &'a mut $ty<T, N> {
+            type Item = &'a mut T;
+            type Iter = rayon::slice::IterMut<'a, T>;
+
+            fn into_par_iter(self) -> Self::Iter {
+                <&mut [T]>::into_par_iter(self)
+            }
+        }
+
+        impl<'a, T: Send, const N: usize> ParallelDrainRange<usize> for &'a mut $ty<T, N> {
+            type Iter = ParDrain<'a, T, $ty<T, N>>;
+            type Item = T;
+
+            /// Removes the specified range from the array in bulk, Returning all removed elements as a parallel iterator.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use stack_array::*;
+            /// use rayon::prelude::*;
+            ///
+            /// let mut arr = ArrayBuf::from([1, 2, 3, 4, 5]);
+            /// let sum: i32 = arr.par_drain(1..4).sum();
+            /// assert_eq!(sum, 9);
+            /// assert_eq!(arr[..], [1, 5]);
+            /// ```
+            fn par_drain<R: RangeBounds<usize>>(self, range: R) -> Self::Iter {
+                ParDrain {
+                    orig_len: self.len(),
+                    range: slice_range(range, ..self.len()),
+                    arr: self,
+                    _marker: core::marker::PhantomData,
+                }
+            }
+        }
+    )*};
+}
+
+impl_par_borrowed!(ArrayBuf, HeapArrayBuf);
+
+impl<T: Send, const N: usize> IntoParallelIterator for ArrayBuf<T, N> {
+    type Item = T;
+    type Iter = IntoParIter<T, N>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        IntoParIter { arr: self }
+    }
+}
+
+/// Extend the array with items from a parallel iterator.
+///
+/// If the iterator is indexed, The items are written directly into the spare capacity.
+/// Otherwise each rayon split collects into its own `ArrayBuf<T, N>` on the worker's stack,
+/// And the chunks are appended to each other. Nothing is allocated on the heap in either case,
+/// Unless the array is larger than 4 KiB, Then the items are collected into
+/// a list of `Vec<T>` first, Like `HeapArrayBuf<T, N>` does.
+///
+/// # Panics
+///
+/// Panics if the number of items exceeds the remaining capacity, No item is added in that case.
+impl<T: Send, const N: usize> ParallelExtend<T> for ArrayBuf<T, N> {
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let par_iter = par_iter.into_par_iter();
+        match par_iter.opt_len() {
+            Some(count) => extend_indexed(self, par_iter, count),
+            None if mem::size_of::<Self>() <= MAX_STACK_CHUNK => extend_chunked(self, par_iter),
+            None => extend_listed(self, par_iter),
+        }
+    }
+}
+
+/// Moves the elements out, The heap allocation is reused as a `Vec<T>`.
+impl<T: Send, const N: usize> IntoParallelIterator for HeapArrayBuf<T, N> {
+    type Item = T;
+    type Iter = rayon::vec::IntoIter<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let heap = mem::ManuallyDrop::new(self);
+        // SAFETY: `buf` is allocated by the global allocator with the layout of `[T; N]`,
+        // That is the layout of a `Vec<T>` with a capacity of `N`, And the first `len` slots are initialized.
+        // `heap` is never dropped, So the `Vec<T>` is the only owner of the allocation.
+        let vec = unsafe {
+            let buf = ptr::read(&heap.buf);
+            Vec::from_raw_parts(Box::into_raw(buf) as *mut T, heap.len, N)
+        };
+        vec.into_par_iter()
+    }
+}
+
+/// Extend the array with items from a parallel iterator.
+///
+/// If the iterator is indexed, The items are written directly into the spare capacity.
+/// Otherwise they are collected into a list of `Vec<T>` first, Like `Vec<T>` does,
+/// Because a chunk of `N` slots per rayon split could be too large for the worker's stack.
+///
+/// # Panics
+///
+/// Panics if the number of items exceeds the remaining capacity, No item is added in that case.
+impl<T: Send, const N: usize> ParallelExtend<T> for HeapArrayBuf<T, N> {
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let par_iter = par_iter.into_par_iter();
+        match par_iter.opt_len() {
+            Some(count) => extend_indexed(self, par_iter, count),
+            None => extend_listed(self, par_iter),
+        }
+    }
+}
+
+/// The largest `ArrayBuf<T, N>`, In bytes, That an unindexed `par_extend` collects on the worker's stack.
+///
+/// Every level of rayon splits keeps a chunk alive, And the worker stacks are only 2 MiB by default.
+const MAX_STACK_CHUNK: usize = 4096;
+
+fn extend_listed<T: Send>(arr: &mut impl Array<T>, par_iter: impl ParallelIterator<Item = T>) {
+    let list = par_iter.collect_vec_list();
+    let count: usize = list.iter().map(Vec::len).sum();
+    arr.ensure_capacity(arr.len() + count);
+    for mut vec in list {
+        arr.append(&mut vec);
+    }
+}
+
+fn extend_indexed<T: Send>(
+    arr: &mut impl Array<T>,
+    par_iter: impl ParallelIterator<Item = T>,
+    count: usize,
+) {
+    let len = arr.len();
+    arr.ensure_capacity(len + count);
+
+    let start = unsafe { arr.as_mut_ptr().add(len) };
+    let result = par_iter.drive_unindexed(CollectConsumer {
+        start,
+        len: count,
+        _marker: PhantomData,
+    });
+    // `opt_len` is only a promise, So check it before taking the ownership of the items.
+    assert_eq!(
+        result.initialized_len, count,
+        "expected {count} items, But got {}",
+        result.initialized_len
+    );
+    mem::forget(result);
+    unsafe { arr.set_len(len + count) };
+}
+
+fn extend_chunked<T: Send, const N: usize>(
+    arr: &mut ArrayBuf<T, N>,
+    par_iter: impl ParallelIterator<Item = T>,
+) {
+    // `Err(count)` means the items doesn't fit in a single chunk, They are dropped and only counted.
+    let chunk = par_iter
+        .fold(
+            || Ok(ArrayBuf::<T, N>::new()),
+            |chunk, item| match chunk {
+                Ok(mut chunk) if !chunk.is_full() => {
+                    chunk.push(item);
+                    Ok(chunk)
+                }
+                Ok(chunk) => Err(chunk.len() + 1),
+                Err(count) => Err(count + 1),
+            },
+        )
+        .reduce(
+            || Ok(ArrayBuf::new()),
+            |left, right| match (left, right) {
+                (Ok(mut left), Ok(mut right)) if left.len() + right.len() <= N => {
+                    left.append(&mut right);
+                    Ok(left)
+                }
+                (left, right) => Err(chunk_len(&left) + chunk_len(&right)),
+            },
+        );
+
+    arr.ensure_capacity(arr.len() + chunk_len(&chunk));
+    if let Ok(mut chunk) = chunk {
+        arr.append(&mut chunk);
+    }
+}
+
+#[inline]
+fn chunk_len<T, const N: usize>(chunk: &Result<ArrayBuf<T, N>, usize>) -> usize {
+    match chunk {
+        Ok(chunk) => chunk.len(),
+        Err(count) => *count,
+    }
+}
+
+/// Writes the items of an indexed parallel iterator into `len` uninitialized slots, Starting at `start`.
+struct CollectConsumer<'a, T> {
+    start: *mut T,
+    len: usize,
+    _marker: PhantomData<&'a mut [T]>,
+}
+
+unsafe impl<T: Send> Send for CollectConsumer<'_, T> {}
+
+impl<'a, T: Send + 'a> Consumer<T> for CollectConsumer<'a, T> {
+    type Folder = CollectResult<'a, T>;
+    type Reducer = CollectReducer;
+    type Result = CollectResult<'a, T>;
+
+    fn split_at(self, index: usize) -> (Self, Self, Self::Reducer) {
+        assert!(index <= self.len);
+        let right = CollectConsumer {
+            start: unsafe { self.start.add(index) },
+            len: self.len - index,
+            _marker: PhantomData,
+        };
+        let left = CollectConsumer { len: index, ..self };
+        (left, right, CollectReducer)
+    }
+
+    fn into_folder(self) -> Self::Folder {
+        CollectResult {
+            start: self.start,
+            total_len: self.len,
+            initialized_len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: Send + 'a> UnindexedConsumer<T> for CollectConsumer<'a, T> {
+    fn split_off_left(&self) -> Self {
+        unreachable!("`CollectConsumer` is only used with indexed iterators")
+    }
+
+    fn to_reducer(&self) -> Self::Reducer {
+        CollectReducer
+    }
+}
+
+/// The initialized prefix of a `CollectConsumer`'s slots, That is dropped if the extend panics.
+struct CollectResult<'a, T> {
+    start: *mut T,
+    total_len: usize,
+    initialized_len: usize,
+    _marker: PhantomData<&'a mut [T]>,
+}
+
+unsafe impl<T: Send> Send for CollectResult<'_, T> {}
+
+impl<'a, T: Send + 'a> Folder<T> for CollectResult<'a, T> {
+    type Result = Self;
+
+    fn consume(mut self, item: T) -> Self {
+        assert!(
+            self.initialized_len < self.total_len,
+            "too many items pushed to consumer"
+        );
+        unsafe { ptr::write(self.start.add(self.initialized_len), item) };
+        self.initialized_len += 1;
+        self
+    }
+
+    fn complete(self) -> Self::Result {
+        self
+    }
+
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+impl<T> Drop for CollectResult<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.start,
+                self.initialized_len,
+            ))
+        };
+    }
+}
+
+struct CollectReducer;
+
+impl<'a, T> Reducer<CollectResult<'a, T>> for CollectReducer {
+    fn reduce(
+        self,
+        mut left: CollectResult<'a, T>,
+        right: CollectResult<'a, T>,
+    ) -> CollectResult<'a, T> {
+        // Merge only if the left side is fully written, So the initialized items stay contiguous.
+        // Otherwise `right` is dropped here, And the length check fails.
+        if unsafe { left.start.add(left.initialized_len) } == right.start {
+            left.total_len += right.total_len;
+            left.initialized_len += right.initialized_len;
+            mem::forget(right);
+        }
+        left
+    }
+}
+
+/// A parallel iterator that moves out of an [`ArrayBuf`].
+#[derive(Debug)]
+pub struct IntoParIter<T, const N: usize> {
+    arr: ArrayBuf<T, N>,
+}
+
+impl<T: Send, const N: usize> ParallelIterator for IntoParIter<T, N> {
+    type Item = T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.arr.len())
+    }
+}
+
+impl<T: Send, const N: usize> IndexedParallelIterator for IntoParIter<T, N> {
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.arr.len()
+    }
+
+    fn with_producer<CB>(mut self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        // Drain every item, Then the array is empty when it is dropped.
+        self.arr.par_drain(..).with_producer(callback)
+    }
+}
+
+/// A parallel draining iterator, Created by `par_drain`.
+///
+/// Like [`Drain`], The un-drained tail is moved back when it is dropped.
+pub struct ParDrain<'a, T, A: Array<T>> {
+    arr: &'a mut A,
+    range: Range<usize>,
+    orig_len: usize,
+    _marker: core::marker::PhantomData<T>,
+}
+
+impl<T: fmt::Debug, A: Array<T>> fmt::Debug for ParDrain<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let slice = if self.arr.len() == self.orig_len {
+            &self.arr.as_slice()[self.range.clone()]
+        } else {
+            &[]
+        };
+        f.debug_tuple("ParDrain").field(&slice).finish()
+    }
+}
+
+impl<T: Send, A: Array<T> + Send> ParallelIterator for ParDrain<'_, T, A> {
+    type Item = T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.range.len())
+    }
+}
+
+impl<T: Send, A: Array<T> + Send> IndexedParallelIterator for ParDrain<'_, T, A> {
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        unsafe {
+            // Make the array forget about the drained items, And temporarily the tail too.
+            self.arr.set_len(self.range.start);
+            // The producer is the exclusive "owner" of the drained items, It will move or drop each of them.
+            let ptr = self.arr.as_mut_ptr().add(self.range.start);
+            let slice = slice::from_raw_parts_mut(ptr, self.range.len());
+            callback.callback(DrainProducer { slice })
+        }
+    }
+}
+
+impl<T, A: Array<T>> Drop for ParDrain<'_, T, A> {
+    fn drop(&mut self) {
+        let Range { start, end } = self.range;
+        if self.arr.len() == self.orig_len {
+            // The producer was never created, So just use a normal drain to remove the items.
+            self.arr.drain(start..end);
+        } else if end < self.orig_len {
+            // The producer was responsible for consuming the drained items.
+            // Move the tail back, Then set the length to include them.
+            unsafe {
+                let tail_len = self.orig_len - end;
+                let ptr = self.arr.as_mut_ptr();
+                ptr::copy(ptr.add(end), ptr.add(start), tail_len);
+                self.arr.set_len(start + tail_len);
+            }
+        }
+    }
+}
+
+struct DrainProducer<'a, T> {
+    slice: &'a mut [T],
+}
+
+impl<'a, T: Send + 'a> Producer for DrainProducer<'a, T> {
+    type Item = T;
+    type IntoIter = SliceDrain<'a, T>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        // Replace the slice, So we don't drop it twice.
+        let slice = mem::take(&mut self.slice);
+        SliceDrain {
+            iter: slice.iter_mut(),
+        }
+    }
+
+    fn split_at(mut self, index: usize) -> (Self, Self) {
+        let slice = mem::take(&mut self.slice);
+        let (left, right) = slice.split_at_mut(index);
+        (
+            DrainProducer { slice: left },
+            DrainProducer { slice: right },
+        )
+    }
+}
+
+impl<T> Drop for DrainProducer<'_, T> {
+    fn drop(&mut self) {
+        let slice: *mut [T] = mem::take::<&mut [T]>(&mut self.slice);
+        unsafe { ptr::drop_in_place(slice) };
+    }
+}
+
+/// Like [`Drain`], Without updating the source array.
+struct SliceDrain<'a, T> {
+    iter: slice::IterMut<'a, T>,
+}
+
+impl<T> Iterator for SliceDrain<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        // Coerce the pointer early, So we don't keep the reference that's about to be invalidated.
+        let ptr: *const T = self.iter.next()?;
+        Some(unsafe { ptr::read(ptr) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for SliceDrain<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        let ptr: *const T = self.iter.next_back()?;
+        Some(unsafe { ptr::read(ptr) })
+    }
+}
+
+impl<T> ExactSizeIterator for SliceDrain<'_, T> {}
+
+impl<T> Drop for SliceDrain<'_, T> {
+    fn drop(&mut self) {
+        let slice: *mut [T] = mem::take(&mut self.iter).into_slice();
+        unsafe { ptr::drop_in_place(slice) };
+    }
+}
@@ -13,13 +13,66 @@ macro_rules! __impl_slice_eq1 {
     }
 }
 
-__impl_slice_eq1! { [const N: usize] ArrayBuf<T, N>, ArrayBuf<U, N>}
+__impl_slice_eq1! { [const N: usize, const M: usize] ArrayBuf<T, N>, ArrayBuf<U, M>}
 __impl_slice_eq1! { [const N: usize] ArrayBuf<T, N>, &[U]}
 __impl_slice_eq1! { [const N: usize] ArrayBuf<T, N>, &mut [U]}
 __impl_slice_eq1! { [const N: usize] &[T], ArrayBuf<U, N>}
 __impl_slice_eq1! { [const N: usize] &mut [T], ArrayBuf<U, N>}
 __impl_slice_eq1! { [const N: usize] ArrayBuf<T, N>, [U] }
 __impl_slice_eq1! { [const N: usize] [T], ArrayBuf<U, N> }
+#[cfg(feature = "std")]
+__impl_slice_eq1! { [const N: usize] ArrayBuf<T, N>, Vec<U> }
+#[cfg(feature = "std")]
+__impl_slice_eq1! { [const N: usize] Vec<T>, ArrayBuf<U, N> }
 
-__impl_slice_eq1! { [const N: usize] ArrayBuf<T, N>, [U; N]}
-__impl_slice_eq1! { [const N: usize] ArrayBuf<T, N>, &[U; N]}
+__impl_slice_eq1! { [const N: usize, const M: usize] ArrayBuf<T, N>, [U; M]}
+__impl_slice_eq1! { [const N: usize, const M: usize] ArrayBuf<T, N>, &[U; M]}
+__impl_slice_eq1! { [const N: usize, const M: usize] [T; M], ArrayBuf<U, N>}
+
+macro_rules! __impl_slice_ord1 {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        /// Implements comparison of vectors, [lexicographically](core::cmp::Ord#lexicographical-comparison).
+        impl<T: PartialOrd, $($vars)*> PartialOrd<$rhs> for $lhs {
+            #[inline]
+            fn partial_cmp(&self, other: &$rhs) -> Option<cmp::Ordering> {
+                PartialOrd::partial_cmp(&self[..], &other[..])
+            }
+        }
+    }
+}
+
+__impl_slice_ord1! { [const N: usize, const M: usize] ArrayBuf<T, N>, ArrayBuf<T, M> }
+#[cfg(feature = "std")]
+__impl_slice_ord1! { [const N: usize] ArrayBuf<T, N>, Vec<T> }
+#[cfg(feature = "std")]
+__impl_slice_ord1! { [const N: usize] Vec<T>, ArrayBuf<T, N> }
+__impl_slice_ord1! { [const N: usize, const M: usize] ArrayBuf<T, N>, [T; M] }
+__impl_slice_ord1! { [const N: usize, const M: usize] [T; M], ArrayBuf<T, N> }
+
+#[cfg(feature = "std")]
+mod heap {
+    use crate::*;
+
+    __impl_slice_eq1! { [const N: usize, const M: usize] HeapArrayBuf<T, N>, HeapArrayBuf<U, M>}
+    __impl_slice_eq1! { [const N: usize, const M: usize] HeapArrayBuf<T, N>, ArrayBuf<U, M>}
+    __impl_slice_eq1! { [const N: usize, const M: usize] ArrayBuf<T, N>, HeapArrayBuf<U, M>}
+    __impl_slice_eq1! { [const N: usize] HeapArrayBuf<T, N>, &[U]}
+    __impl_slice_eq1! { [const N: usize] HeapArrayBuf<T, N>, &mut [U]}
+    __impl_slice_eq1! { [const N: usize] &[T], HeapArrayBuf<U, N>}
+    __impl_slice_eq1! { [const N: usize] &mut [T], HeapArrayBuf<U, N>}
+    __impl_slice_eq1! { [const N: usize] HeapArrayBuf<T, N>, [U] }
+    __impl_slice_eq1! { [const N: usize] [T], HeapArrayBuf<U, N> }
+    __impl_slice_eq1! { [const N: usize] HeapArrayBuf<T, N>, Vec<U> }
+    __impl_slice_eq1! { [const N: usize] Vec<T>, HeapArrayBuf<U, N> }
+    __impl_slice_eq1! { [const N: usize, const M: usize] HeapArrayBuf<T, N>, [U; M]}
+    __impl_slice_eq1! { [const N: usize, const M: usize] HeapArrayBuf<T, N>, &[U; M]}
+    __impl_slice_eq1! { [const N: usize, const M: usize] [T; M], HeapArrayBuf<U, N>}
+
+    __impl_slice_ord1! { [const N: usize, const M: usize] HeapArrayBuf<T, N>, HeapArrayBuf<T, M> }
+    __impl_slice_ord1! { [const N: usize, const M: usize] HeapArrayBuf<T, N>, ArrayBuf<T, M> }
+    __impl_slice_ord1! { [const N: usize, const M: usize] ArrayBuf<T, N>, HeapArrayBuf<T, M> }
+    __impl_slice_ord1! { [const N: usize] HeapArrayBuf<T, N>, Vec<T> }
+    __impl_slice_ord1! { [const N: usize] Vec<T>, HeapArrayBuf<T, N> }
+    __impl_slice_ord1! { [const N: usize, const M: usize] HeapArrayBuf<T, N>, [T; M] }
+    __impl_slice_ord1! { [const N: usize, const M: usize] [T; M], HeapArrayBuf<T, N> }
+}
@@ -0,0 +1,69 @@
+use crate::*;
+use bytemuck::{checked::CheckedCastError, AnyBitPattern, Pod, PodCastError, Zeroable};
+
+// SAFETY: All zeroes is an empty array, `buf` is never read beyond `len`.
+unsafe impl<T, const N: usize> Zeroable for ArrayBuf<T, N> {}
+
+impl<T: Pod, const N: usize> ArrayBuf<T, N> {
+    /// Returns the bytes of the initialized elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    ///
+    /// let arr: ArrayBuf<u16, 4> = ArrayBuf::from([1, 2].as_slice());
+    /// assert_eq!(arr.as_bytes(), [1_u16.to_ne_bytes(), 2_u16.to_ne_bytes()].concat());
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Returns the bytes of the initialized elements, As a mutable slice.
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self.as_mut_slice())
+    }
+}
+
+impl<T: AnyBitPattern, const N: usize> ArrayBuf<T, N> {
+    /// Reinterprets `bytes` as an `ArrayBuf`, Using the `#[repr(C)]` layout of `ArrayBuf`.
+    ///
+    /// # Errors
+    ///
+    /// - [`PodCastError::SizeMismatch`], If `bytes.len()` is not `size_of::<Self>()`.
+    /// - [`PodCastError::TargetAlignmentGreaterAndInputNotAligned`], If `bytes` is not aligned to `align_of::<Self>()`.
+    /// - [`CheckedCastError::InvalidBitPattern`], If the length is greater than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    /// use bytemuck::checked::CheckedCastError;
+    ///
+    /// // `len` followed by one slot.
+    /// let words: [usize; 2] = [1, 42];
+    /// let arr = ArrayBuf::<usize, 1>::try_from_bytes(bytemuck::bytes_of(&words)).unwrap();
+    /// assert_eq!(arr.as_slice(), [42]);
+    ///
+    /// let words: [usize; 2] = [2, 42];
+    /// let err = ArrayBuf::<usize, 1>::try_from_bytes(bytemuck::bytes_of(&words)).unwrap_err();
+    /// assert_eq!(err, CheckedCastError::InvalidBitPattern);
+    /// ```
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<&Self, CheckedCastError> {
+        if bytes.len() != mem::size_of::<Self>() {
+            return Err(PodCastError::SizeMismatch.into());
+        }
+        if !(bytes.as_ptr() as usize).is_multiple_of(mem::align_of::<Self>()) {
+            return Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned.into());
+        }
+        // SAFETY: `len` is the first field of `#[repr(C)]` struct, And `bytes` is aligned to `Self`.
+        let len = unsafe { ptr::read(bytes.as_ptr() as *const usize) };
+        if len > N {
+            return Err(CheckedCastError::InvalidBitPattern);
+        }
+        // SAFETY: Size and alignment are checked, And every initialized element is valid for any bit pattern.
+        Ok(unsafe { &*(bytes.as_ptr() as *const Self) })
+    }
+}
@@ -3,6 +3,15 @@ use crate::*;
 pub fn retain_mut<F, Arr: Array<T>, T>(this: &mut Arr, mut f: F)
 where
     F: FnMut(&mut T) -> bool,
+{
+    retain_with(this, |_, elem| Some(f(elem)))
+}
+
+/// Like `retain_mut`, But `f` also get the original index of the element,
+/// And can stop early by returning `None`, The current element and the rest are kept in that case.
+pub fn retain_with<F, Arr: Array<T>, T>(this: &mut Arr, mut f: F)
+where
+    F: FnMut(usize, &mut T) -> Option<bool>,
 {
     let original_len = this.len();
     // Avoid double drop if the drop guard is not executed,
@@ -57,17 +66,23 @@ where
         _marker: core::marker::PhantomData,
     };
 
+    // Returns `true` if `f` stopped early.
     fn process_loop<F, Arr: Array<T>, T, const DELETED: bool>(
         original_len: usize,
         f: &mut F,
         g: &mut BackshiftOnDrop<'_, Arr, T>,
-    ) where
-        F: FnMut(&mut T) -> bool,
+    ) -> bool
+    where
+        F: FnMut(usize, &mut T) -> Option<bool>,
     {
         while g.processed_len != original_len {
             // SAFETY: Unchecked element must be valid.
             let cur = unsafe { &mut *g.v.as_mut_ptr().add(g.processed_len) };
-            if !f(cur) {
+            let Some(keep) = f(g.processed_len, cur) else {
+                // The guard shifts the current element and the rest to cover holes.
+                return true;
+            };
+            if !keep {
                 // Advance early to avoid double drop if `drop_in_place` panicked.
                 g.processed_len += 1;
                 g.deleted_cnt += 1;
@@ -77,7 +92,7 @@ where
                 if DELETED {
                     continue;
                 } else {
-                    break;
+                    return false;
                 }
             }
             if DELETED {
@@ -90,13 +105,14 @@ where
             }
             g.processed_len += 1;
         }
+        false
     }
 
     // Stage 1: Nothing was deleted.
-    process_loop::<F, Arr, T, false>(original_len, &mut f, &mut g);
-
-    // Stage 2: Some elements were deleted.
-    process_loop::<F, Arr, T, true>(original_len, &mut f, &mut g);
+    if !process_loop::<F, Arr, T, false>(original_len, &mut f, &mut g) {
+        // Stage 2: Some elements were deleted.
+        process_loop::<F, Arr, T, true>(original_len, &mut f, &mut g);
+    }
 
     // All item are processed. This can be optimized to `set_len` by LLVM.
     drop(g);
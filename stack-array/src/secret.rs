@@ -0,0 +1,420 @@
+use crate::*;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+macro_rules! impl_zeroize {
+    ($($ty:ident),*) => {$(
+        /// Zeroize all the elements, Then the entire storage, Including the uninitialized tail.
+        ///
+        /// The array is empty afterward. Only an explicit `zeroize()` wipes the storage,
+        /// Use [`SecretArrayBuf`] to also wipe it on drop, And whenever an element is removed.
+        impl<T: Zeroize, const N: usize> Zeroize for $ty<T, N> {
+            fn zeroize(&mut self) {
+                self.iter_mut().zeroize();
+                self.clear();
+                self.buf[..].zeroize();
+            }
+        }
+
+        /// Like `Vec<T>`, Each element is responsible for zeroizing itself on drop.
+        ///
+        /// So this only applies if `T: ZeroizeOnDrop`, Plain bytes such as `u8` aren't wiped on drop,
+        /// And neither is the uninitialized tail. Use [`SecretArrayBuf`] for that.
+        impl<T: ZeroizeOnDrop, const N: usize> ZeroizeOnDrop for $ty<T, N> {}
+    )*};
+}
+
+impl_zeroize!(ArrayBuf);
+#[cfg(feature = "std")]
+impl_zeroize!(HeapArrayBuf);
+
+/// An [`ArrayBuf`] for secrets, Such as key material.
+///
+/// Every slot that leaves the initialized range is wiped with volatile writes,
+/// Including the moved-from slots in `remove`, `swap_remove` and `retain`.
+/// Removed elements are [zeroized](Zeroize) before they are dropped,
+/// And the entire storage is wiped on drop.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+///
+/// let mut key: SecretArrayBuf<u8, 32> = SecretArrayBuf::new();
+/// key.extend_from_slice(b"secret");
+/// key.truncate(3);
+///
+/// assert_eq!(key.as_slice(), b"sec");
+/// ```
+pub struct SecretArrayBuf<T: Zeroize, const N: usize> {
+    arr: ArrayBuf<T, N>,
+}
+
+impl<T: Zeroize, const N: usize> SecretArrayBuf<T, N> {
+    /// Constructs a new, empty `SecretArrayBuf`
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            arr: ArrayBuf::new(),
+        }
+    }
+
+    /// Returns the number of elements the array can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of elements currently in the array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.arr.len()
+    }
+
+    /// Returns true if the array contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.arr.is_empty()
+    }
+
+    /// Returns `true`, If the array is full.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.arr.is_full()
+    }
+
+    /// Extracts a slice containing the entire array.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.arr.as_slice()
+    }
+
+    /// Extracts a mutable slice of the entire array.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.arr.as_mut_slice()
+    }
+
+    /// Wipe the (uninitialized) slots in `range` with volatile writes.
+    #[inline]
+    fn wipe(&mut self, range: Range<usize>) {
+        self.arr.buf[range].zeroize();
+    }
+
+    /// Appends an element to the back of the array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is full.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.arr.push(value)
+    }
+
+    /// Copies all elements of `other` to the back of the array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array doesn't have enough capacity.
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: impl AsRef<[T]>)
+    where
+        T: Copy,
+    {
+        self.arr.extend_from_slice(other)
+    }
+
+    /// Inserts an element at position index within the array, Shifting all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds, Or the array is full.
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.arr.insert(index, element)
+    }
+
+    /// Removes the last element and returns it, The vacated slot is wiped.
+    pub fn pop(&mut self) -> Option<T> {
+        let value = self.arr.pop()?;
+        self.wipe(self.len()..self.len() + 1);
+        Some(value)
+    }
+
+    /// Removes an element from position index and returns it, Shifting all elements after it to the left.
+    ///
+    /// The vacated slot at the end is wiped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        let value = self.arr.remove(index);
+        self.wipe(self.len()..self.len() + 1);
+        value
+    }
+
+    /// Removes an element and returns it, The removed element is replaced by the last element.
+    ///
+    /// The vacated slot at the end is wiped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let value = self.arr.swap_remove(index);
+        self.wipe(self.len()..self.len() + 1);
+        value
+    }
+
+    /// Shortens the array, Keeping the first `len` elements.
+    ///
+    /// The rest are zeroized and dropped, Then their slots are wiped.
+    pub fn truncate(&mut self, len: usize) {
+        let old_len = self.len();
+        if len >= old_len {
+            return;
+        }
+        self.arr[len..].iter_mut().zeroize();
+        self.arr.truncate(len);
+        self.wipe(len..old_len);
+    }
+
+    /// Clears the array, Zeroizing and removing all values.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// Removed elements are zeroized and dropped, The vacated slots at the end are wiped.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        // Wipe the vacated slots, Even if `f` panics.
+        struct WipeOnDrop<'a, T: Zeroize, const N: usize> {
+            this: &'a mut SecretArrayBuf<T, N>,
+            old_len: usize,
+        }
+        impl<T: Zeroize, const N: usize> Drop for WipeOnDrop<'_, T, N> {
+            fn drop(&mut self) {
+                let len = self.this.len();
+                self.this.wipe(len..self.old_len);
+            }
+        }
+
+        let guard = WipeOnDrop {
+            old_len: self.len(),
+            this: self,
+        };
+        retain_mut(&mut guard.this.arr, |elem| {
+            let keep = f(elem);
+            if !keep {
+                elem.zeroize();
+            }
+            keep
+        });
+    }
+
+    /// Removes the specified range from the array in bulk, Returning all removed elements as an iterator.
+    ///
+    /// When the iterator is dropped, Un-yielded elements are zeroized and dropped,
+    /// And the vacated slots at the end are wiped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the array.
+    pub fn drain<R>(&mut self, range: R) -> SecretDrain<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let old_len = self.len();
+        let arr = NonNull::from(&mut self.arr);
+        // SAFETY: `arr` is derived from `&mut self`, And `SecretDrain` borrows `self` for its lifetime.
+        let iter = unsafe { (*arr.as_ptr()).drain(range) };
+        SecretDrain {
+            iter: mem::ManuallyDrop::new(iter),
+            arr,
+            old_len,
+        }
+    }
+}
+
+/// A draining iterator for [`SecretArrayBuf`], Created by [`SecretArrayBuf::drain`].
+pub struct SecretDrain<'a, T: Zeroize, const N: usize> {
+    iter: mem::ManuallyDrop<Drain<'a, T, ArrayBuf<T, N>>>,
+    arr: NonNull<ArrayBuf<T, N>>,
+    old_len: usize,
+}
+
+impl<T: Zeroize, const N: usize> Iterator for SecretDrain<'_, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T: Zeroize, const N: usize> DoubleEndedIterator for SecretDrain<'_, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+impl<T: Zeroize, const N: usize> Drop for SecretDrain<'_, T, N> {
+    fn drop(&mut self) {
+        for mut value in &mut *self.iter {
+            value.zeroize();
+        }
+        unsafe {
+            // SAFETY: `iter` is never used again, After that, `arr` is no longer borrowed by it.
+            mem::ManuallyDrop::drop(&mut self.iter);
+            let arr = self.arr.as_mut();
+            arr.buf[arr.len..self.old_len].zeroize();
+        }
+    }
+}
+
+impl<T: Zeroize, const N: usize> Zeroize for SecretArrayBuf<T, N> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.arr.zeroize();
+    }
+}
+
+impl<T: Zeroize, const N: usize> ZeroizeOnDrop for SecretArrayBuf<T, N> {}
+
+impl<T: Zeroize, const N: usize> Drop for SecretArrayBuf<T, N> {
+    fn drop(&mut self) {
+        self.arr.zeroize();
+    }
+}
+
+impl<T: Zeroize, const N: usize> Default for SecretArrayBuf<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Zeroize, const N: usize> From<ArrayBuf<T, N>> for SecretArrayBuf<T, N> {
+    /// Note: The memory `arr` was moved from is not wiped.
+    #[inline]
+    fn from(arr: ArrayBuf<T, N>) -> Self {
+        Self { arr }
+    }
+}
+
+impl<T: Zeroize, const N: usize> Deref for SecretArrayBuf<T, N> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T: Zeroize, const N: usize> DerefMut for SecretArrayBuf<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Zeroize, const N: usize> AsRef<[T]> for SecretArrayBuf<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Zeroize, const N: usize> AsMut<[T]> for SecretArrayBuf<T, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+/// Doesn't print the elements.
+impl<T: Zeroize, const N: usize> fmt::Debug for SecretArrayBuf<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretArrayBuf")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the first `M` slots of the storage, They must have been written at least once.
+    fn raw<const M: usize, const N: usize>(arr: &SecretArrayBuf<u8, N>) -> [u8; M] {
+        core::array::from_fn(|i| unsafe { arr.arr.buf[i].assume_init() })
+    }
+
+    fn secret() -> SecretArrayBuf<u8, 8> {
+        let mut arr = SecretArrayBuf::new();
+        arr.extend_from_slice([1, 2, 3, 4, 5, 6]);
+        arr
+    }
+
+    #[test]
+    fn test_truncate_pop() {
+        let mut arr = secret();
+        arr.truncate(4);
+        assert_eq!(raw::<6, 8>(&arr), [1, 2, 3, 4, 0, 0]);
+
+        assert_eq!(arr.pop(), Some(4));
+        assert_eq!(raw::<6, 8>(&arr), [1, 2, 3, 0, 0, 0]);
+
+        arr.clear();
+        assert_eq!(raw::<6, 8>(&arr), [0; 6]);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut arr = secret();
+        assert_eq!(arr.remove(0), 1);
+        assert_eq!(raw::<6, 8>(&arr), [2, 3, 4, 5, 6, 0]);
+
+        assert_eq!(arr.swap_remove(0), 2);
+        assert_eq!(raw::<6, 8>(&arr), [6, 3, 4, 5, 0, 0]);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut arr = secret();
+        arr.retain(|x| x % 2 == 0);
+        assert_eq!(raw::<6, 8>(&arr), [2, 4, 6, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut arr = secret();
+        assert!(arr.drain(1..3).eq([2, 3]));
+        assert_eq!(raw::<6, 8>(&arr), [1, 4, 5, 6, 0, 0]);
+
+        drop(arr.drain(..2));
+        assert_eq!(raw::<6, 8>(&arr), [5, 6, 0, 0, 0, 0]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_retain_panic() {
+        let mut arr = secret();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            arr.retain(|&x| if x == 4 { panic!() } else { x % 2 == 0 })
+        }));
+        assert!(result.is_err());
+        assert_eq!(arr.as_slice(), [2, 4, 5, 6]);
+        assert_eq!(raw::<6, 8>(&arr), [2, 4, 5, 6, 0, 0]);
+    }
+}
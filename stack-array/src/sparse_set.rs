@@ -0,0 +1,207 @@
+use crate::*;
+
+/// A set of integer ids in `0..N`, Each mapped to a value, Stored inline.
+///
+/// The values are packed in a dense [`ArrayBuf`], So iterating over them is as fast as iterating over a slice,
+/// While [`insert`](SparseSet::insert), [`remove`](SparseSet::remove), [`contains`](SparseSet::contains)
+/// and [`get`](SparseSet::get) are `O(1)`. Removing an id moves the last value into its place,
+/// Like [`Array::swap_remove`], So the dense order isn't preserved.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+///
+/// let mut positions: SparseSet<(i32, i32), 64> = SparseSet::new();
+/// positions.insert(7, (0, 0));
+/// positions.insert(42, (3, 4));
+///
+/// assert!(positions.contains(42));
+/// assert_eq!(positions.get(7), Some(&(0, 0)));
+///
+/// for (x, y) in positions.values_mut() {
+///     *x += 1;
+///     *y += 1;
+/// }
+/// assert_eq!(positions.remove(7), Some((1, 1)));
+/// assert_eq!(positions.ids(), [42]);
+/// ```
+pub struct SparseSet<T, const N: usize> {
+    /// Maps an id to its index in `dense`, Only valid if `ids` agrees.
+    sparse: [usize; N],
+    ids: ArrayBuf<usize, N>,
+    dense: ArrayBuf<T, N>,
+}
+
+impl<T, const N: usize> SparseSet<T, N> {
+    /// Constructs a new, Empty `SparseSet`
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            sparse: [0; N],
+            ids: ArrayBuf::new(),
+            dense: ArrayBuf::new(),
+        }
+    }
+
+    /// Returns the number of ids in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns the number of ids the set can hold, That is `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true`, If the set contains no ids.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns `true`, If the set contains `id`.
+    #[inline]
+    pub fn contains(&self, id: usize) -> bool {
+        self.index_of(id).is_some()
+    }
+
+    /// Returns a reference to the value of `id`, Or `None` if the set doesn't contain it.
+    #[inline]
+    pub fn get(&self, id: usize) -> Option<&T> {
+        self.index_of(id).map(|index| &self.dense[index])
+    }
+
+    /// Returns a mutable reference to the value of `id`, Or `None` if the set doesn't contain it.
+    #[inline]
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut T> {
+        self.index_of(id).map(|index| &mut self.dense[index])
+    }
+
+    /// Inserts `id` with the value, Returns the old value if the set already contained `id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id >= N`.
+    pub fn insert(&mut self, id: usize, value: T) -> Option<T> {
+        if id >= N {
+            panic!("id (is {id}) should be < capacity (is {N})");
+        }
+        if let Some(index) = self.index_of(id) {
+            return Some(mem::replace(&mut self.dense[index], value));
+        }
+        self.sparse[id] = self.ids.len();
+        self.ids.push(id);
+        self.dense.push(value);
+        None
+    }
+
+    /// Removes `id` and returns its value, Or `None` if the set doesn't contain it.
+    ///
+    /// The last value in the dense order is moved into its place.
+    pub fn remove(&mut self, id: usize) -> Option<T> {
+        let index = self.index_of(id)?;
+        self.ids.swap_remove(index);
+        if let Some(&moved) = self.ids.get(index) {
+            self.sparse[moved] = index;
+        }
+        Some(self.dense.swap_remove(index))
+    }
+
+    /// Removes all the ids, Dropping the values.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.ids.clear();
+        self.dense.clear();
+    }
+
+    /// Returns the ids, In the dense order.
+    #[inline]
+    pub fn ids(&self) -> &[usize] {
+        &self.ids
+    }
+
+    /// Returns the values, In the dense order.
+    #[inline]
+    pub fn values(&self) -> &[T] {
+        &self.dense
+    }
+
+    /// Returns the values as a mutable slice, In the dense order.
+    #[inline]
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.dense
+    }
+
+    /// Returns an iterator over the ids and their values, In the dense order.
+    #[inline]
+    pub fn iter(&self) -> iter::Zip<iter::Copied<slice::Iter<'_, usize>>, slice::Iter<'_, T>> {
+        self.ids.iter().copied().zip(self.dense.iter())
+    }
+
+    /// Returns an iterator over the ids and mutable references to their values, In the dense order.
+    #[inline]
+    pub fn iter_mut(
+        &mut self,
+    ) -> iter::Zip<iter::Copied<slice::Iter<'_, usize>>, slice::IterMut<'_, T>> {
+        self.ids.iter().copied().zip(self.dense.iter_mut())
+    }
+
+    #[inline]
+    fn index_of(&self, id: usize) -> Option<usize> {
+        let index = *self.sparse.get(id)?;
+        (self.ids.get(index) == Some(&id)).then_some(index)
+    }
+}
+
+impl<T, const N: usize> Default for SparseSet<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SparseSet<T, N> {
+    fn clone(&self) -> Self {
+        self.iter().map(|(id, value)| (id, value.clone())).collect()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SparseSet<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Extend<(usize, T)> for SparseSet<T, N> {
+    /// # Panics
+    ///
+    /// Panics if any id is `>= N`.
+    fn extend<I: IntoIterator<Item = (usize, T)>>(&mut self, iter: I) {
+        for (id, value) in iter {
+            self.insert(id, value);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<(usize, T)> for SparseSet<T, N> {
+    /// # Panics
+    ///
+    /// Panics if any id is `>= N`.
+    fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SparseSet<T, N> {
+    type Item = (usize, &'a T);
+    type IntoIter = iter::Zip<iter::Copied<slice::Iter<'a, usize>>, slice::Iter<'a, T>>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
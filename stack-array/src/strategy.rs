@@ -0,0 +1,109 @@
+//! [proptest](https://docs.rs/proptest) strategies for [`ArrayBuf`], [`HeapArrayBuf`] and [`Op`].
+
+use crate::{differential::Op, *};
+use proptest::{
+    collection::{vec, SizeRange},
+    prelude::*,
+};
+
+/// Create a strategy to generate an [`ArrayBuf`], Whose length is within `size`.
+///
+/// # Panics
+///
+/// Panics if `size` allows a length greater than `N`.
+///
+/// # Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use stack_array::*;
+///
+/// proptest!(|(arr in strategy::array_buf::<_, 8>(any::<u8>(), 1..=8))| {
+///     prop_assert!(!arr.is_empty());
+/// });
+/// ```
+pub fn array_buf<S, const N: usize>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = ArrayBuf<S::Value, N>>
+where
+    S: Strategy,
+{
+    vec(element, within_capacity(size, N)).prop_map(|values| {
+        let mut arr = ArrayBuf::new();
+        for value in values {
+            arr.push(value);
+        }
+        arr
+    })
+}
+
+/// Create a strategy to generate a [`HeapArrayBuf`], Whose length is within `size`.
+///
+/// # Panics
+///
+/// Panics if `size` allows a length greater than `N`.
+///
+/// # Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use stack_array::*;
+///
+/// proptest!(|(arr in strategy::heap_array_buf::<_, 8>(any::<u8>(), 1..=8))| {
+///     prop_assert!(!arr.is_empty());
+/// });
+/// ```
+pub fn heap_array_buf<S, const N: usize>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = HeapArrayBuf<S::Value, N>>
+where
+    S: Strategy,
+{
+    vec(element, within_capacity(size, N)).prop_map(|values| {
+        let mut arr = HeapArrayBuf::new();
+        for value in values {
+            arr.push(value);
+        }
+        arr
+    })
+}
+
+fn within_capacity(size: impl Into<SizeRange>, capacity: usize) -> SizeRange {
+    let size = size.into();
+    assert!(
+        size.end_incl() <= capacity,
+        "length range ({size:?}) exceeds the capacity ({capacity})"
+    );
+    size
+}
+
+/// Create a strategy to generate an [`Op`], Using `element` for the elements it inserts.
+///
+/// # Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use stack_array::*;
+/// use stack_array::differential::check;
+///
+/// proptest!(|(ops in prop::collection::vec(strategy::op(any::<u8>()), 0..64))| {
+///     check(ArrayBuf::<u8, 8>::new(), ops);
+/// });
+/// ```
+pub fn op<S>(element: S) -> impl Strategy<Value = Op<S::Value>>
+where
+    S: Strategy + Clone,
+{
+    prop_oneof![
+        element.clone().prop_map(Op::Push),
+        (any::<usize>(), element.clone()).prop_map(|(index, value)| Op::Insert(index, value)),
+        any::<usize>().prop_map(Op::Remove),
+        (any::<usize>(), any::<usize>()).prop_map(|(start, count)| Op::Drain(start, count)),
+        any::<u64>().prop_map(Op::Retain),
+        any::<u64>().prop_map(Op::DedupBy),
+        vec(element, 0..8).prop_map(Op::Append),
+        any::<usize>().prop_map(Op::Truncate),
+    ]
+}
@@ -0,0 +1,49 @@
+use crate::*;
+use ufmt::{uDebug, uWrite, Formatter};
+
+/// Writes the elements like a slice, E.g. `[1, 2, 3]`, Through `ufmt` rather than `core::fmt`,
+/// Which keeps the formatting machinery out of small binaries.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+///
+/// let arr = ArrayBuf::from([1, 2, 3]);
+/// let mut out: ArrayBuf<u8, 16> = ArrayBuf::new();
+/// ufmt::uwrite!(out, "{:?}", arr).unwrap();
+/// assert_eq!(out, *b"[1, 2, 3]");
+/// ```
+impl<T: uDebug, const N: usize> uDebug for ArrayBuf<T, N> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uDebug::fmt(&**self, f)
+    }
+}
+
+/// Appends the bytes of the string, So `ufmt::uwrite!` can format into a byte buffer.
+///
+/// Nothing is written if the string doesn't fit, And [`CapacityError`] is returned instead of panicking.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+/// use ufmt::uWrite;
+///
+/// let mut buf: ArrayBuf<u8, 8> = ArrayBuf::new();
+/// buf.write_str("Hello").unwrap();
+/// assert!(buf.write_str(", World").is_err());
+/// assert_eq!(buf, *b"Hello");
+/// ```
+impl<const N: usize> uWrite for ArrayBuf<u8, N> {
+    type Error = CapacityError;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        let total_len = self.len() + s.len();
+        if total_len > N {
+            return Err(CapacityError::new(total_len, N));
+        }
+        self.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+use crate::*;
+
+/// A disjoint-set forest over the elements `0..N`, Stored inline.
+///
+/// Every element starts in its own set. [`find`](UnionFind::find) compresses the paths it walks,
+/// And [`union`](UnionFind::union) attaches the lower ranked tree under the higher one,
+/// So both run in nearly constant amortized time.
+///
+/// # Examples
+///
+/// ```
+/// use stack_array::*;
+///
+/// let mut uf: UnionFind<5> = UnionFind::new();
+/// uf.union(0, 1);
+/// uf.union(3, 4);
+/// uf.union(1, 4);
+///
+/// assert!(uf.connected(0, 3));
+/// assert_eq!(uf.set_size(0), 4);
+/// assert_eq!(uf.count_sets(), 2);
+///
+/// let groups: Vec<_> = uf.components().collect();
+/// assert_eq!(groups[0], [0, 1, 3, 4]);
+/// assert_eq!(groups[1], [2]);
+/// ```
+#[derive(Clone)]
+pub struct UnionFind<const N: usize> {
+    parent: [usize; N],
+    rank: [u8; N],
+    /// Number of elements in the set, Only valid for the roots.
+    size: [usize; N],
+    sets: usize,
+}
+
+impl<const N: usize> UnionFind<N> {
+    /// Constructs a new `UnionFind`, Where every element is in its own set.
+    pub const fn new() -> Self {
+        let mut parent = [0; N];
+        let mut i = 0;
+        while i < N {
+            parent[i] = i;
+            i += 1;
+        }
+        Self {
+            parent,
+            rank: [0; N],
+            size: [1; N],
+            sets: N,
+        }
+    }
+
+    /// Returns the number of elements, That is `N`.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true`, If `N` is `0`.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Like [`find`](UnionFind::find), But without compressing the path.
+    fn root(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Returns the smallest element of the set containing each element.
+    fn smallest(&self) -> [usize; N] {
+        let mut smallest = [N; N];
+        let mut labels = [0; N];
+        for (x, label) in labels.iter_mut().enumerate() {
+            let root = self.root(x);
+            if smallest[root] == N {
+                smallest[root] = x;
+            }
+            *label = smallest[root];
+        }
+        labels
+    }
+
+    /// Returns the representative element of the set containing `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x >= N`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut x = x;
+        while self.parent[x] != root {
+            x = mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`.
+    ///
+    /// Returns `true`, If they were in different sets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a >= N` or `b >= N`.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.rank[a] < self.rank[b] {
+            mem::swap(&mut a, &mut b);
+        }
+        if self.rank[a] == self.rank[b] {
+            self.rank[a] += 1;
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.sets -= 1;
+        true
+    }
+
+    /// Returns `true`, If `a` and `b` are in the same set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a >= N` or `b >= N`.
+    #[inline]
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the number of elements in the set containing `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x >= N`.
+    #[inline]
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Returns the number of disjoint sets.
+    #[inline]
+    pub const fn count_sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Puts every element back in its own set.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns an iterator over the sets, Each as an ascending `ArrayBuf` of its elements.
+    ///
+    /// The sets are ordered by their smallest element.
+    pub fn components(&mut self) -> Components<'_, N> {
+        let mut first = [N; N];
+        let mut link = [N; N];
+        // In reverse, So each set is linked in ascending order.
+        for x in (0..N).rev() {
+            let root = self.find(x);
+            link[x] = first[root];
+            first[root] = x;
+        }
+        Components {
+            remaining: self.sets,
+            uf: self,
+            next: 0,
+            first,
+            link,
+        }
+    }
+}
+
+impl<const N: usize> Default for UnionFind<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Two `UnionFind` are equal, If they partition the elements into the same sets,
+/// Regardless of the order of the unions, And which elements are the roots.
+impl<const N: usize> PartialEq for UnionFind<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.sets == other.sets && self.smallest() == other.smallest()
+    }
+}
+
+impl<const N: usize> Eq for UnionFind<N> {}
+
+impl<const N: usize> fmt::Debug for UnionFind<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnionFind")
+            .field("parent", &self.parent)
+            .field("sets", &self.sets)
+            .finish()
+    }
+}
+
+/// An iterator over the sets of a [`UnionFind`].
+///
+/// This struct is created by [`UnionFind::components`].
+pub struct Components<'a, const N: usize> {
+    /// Every path is compressed, So `parent` points directly at the root.
+    uf: &'a UnionFind<N>,
+    next: usize,
+    remaining: usize,
+    /// Smallest element of each set, Indexed by its root.
+    first: [usize; N],
+    /// Next larger element in the same set, Or `N` after the largest.
+    link: [usize; N],
+}
+
+impl<const N: usize> Iterator for Components<'_, N> {
+    type Item = ArrayBuf<usize, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let parent = &self.uf.parent;
+        let first = (self.next..N).find(|&x| self.first[parent[x]] == x)?;
+        self.next = first + 1;
+        self.remaining -= 1;
+
+        let mut set = ArrayBuf::new();
+        let mut x = first;
+        while x < N {
+            set.push(x);
+            x = self.link[x];
+        }
+        Some(set)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for Components<'_, N> {}
@@ -1,117 +1,188 @@
-impl<T> crate::Array<T> for std::vec::Vec<T> {
-    #[inline]
-    fn capacity(&self) -> usize {
-        Vec::capacity(self)
-    }
-
-    #[inline]
-    fn truncate(&mut self, len: usize) {
-        Vec::truncate(self, len)
-    }
-
-    #[inline]
-    fn as_ptr(&self) -> *const T {
-        Vec::as_ptr(self)
-    }
-
-    #[inline]
-    fn as_mut_ptr(&mut self) -> *mut T {
-        Vec::as_mut_ptr(self)
-    }
-
-    #[inline]
-    unsafe fn set_len(&mut self, len: usize) {
-        Vec::set_len(self, len)
-    }
-
-    #[inline]
-    fn as_slice(&self) -> &[T] {
-        Vec::as_slice(self)
-    }
-
-    #[inline]
-    fn as_mut_slice(&mut self) -> &mut [T] {
-        Vec::as_mut_slice(self)
-    }
-
-    #[inline]
-    fn swap_remove(&mut self, index: usize) -> T {
-        Vec::swap_remove(self, index)
-    }
-
-    #[inline]
-    fn insert(&mut self, index: usize, element: T) {
-        Vec::insert(self, index, element)
-    }
-
-    #[inline]
-    fn remove(&mut self, index: usize) -> T {
-        Vec::remove(self, index)
-    }
-
-    #[inline]
-    fn retain<F>(&mut self, f: F)
-    where
-        F: FnMut(&T) -> bool,
-    {
-        Vec::retain(self, f)
-    }
-
-    #[inline]
-    fn dedup(&mut self)
-    where
-        T: PartialEq,
-    {
-        Vec::dedup(self)
-    }
-
-    #[inline]
-    fn dedup_by_key<F, K>(&mut self, key: F)
-    where
-        F: FnMut(&mut T) -> K,
-        K: PartialEq,
-    {
-        Vec::dedup_by_key(self, key)
-    }
-
-    #[inline]
-    fn dedup_by<F>(&mut self, same_bucket: F)
-    where
-        F: FnMut(&mut T, &mut T) -> bool,
-    {
-        Vec::dedup_by(self, same_bucket)
-    }
-
-    #[inline]
-    fn push(&mut self, value: T) {
-        Vec::push(self, value)
-    }
-
-    #[inline]
-    fn clear(&mut self) {
-        Vec::clear(self)
-    }
-
-    #[inline]
-    fn len(&self) -> usize {
-        Vec::len(self)
-    }
-
-    #[inline]
-    fn is_empty(&self) -> bool {
-        Vec::is_empty(self)
-    }
-
-    #[inline]
-    fn pop(&mut self) -> Option<T> {
-        Vec::pop(self)
-    }
-
-    // =========================================================================
-    #[inline]
-    fn ensure_capacity(&mut self, new_len: usize) {
-        if new_len > self.capacity() {
-            Vec::reserve(self, new_len - self.len())
+macro_rules! impl_array_for_vec {
+    ([$($vars:tt)*] $ty:ty) => {
+        // SAFETY: Every method forwards to the `Vec` method of the same name, That uphold the same invariants.
+        // `ensure_capacity` may reallocate, But it isn't `set_len` or a write through `as_mut_ptr`.
+        // On success, `reserve` and `try_reserve` leave `capacity() >= len() + additional`.
+        unsafe impl<T, $($vars)*> crate::Array<T> for $ty {
+            #[inline]
+            fn capacity(&self) -> usize {
+                <$ty>::capacity(self)
+            }
+
+            #[inline]
+            fn truncate(&mut self, len: usize) {
+                <$ty>::truncate(self, len)
+            }
+
+            #[inline]
+            fn as_ptr(&self) -> *const T {
+                <$ty>::as_ptr(self)
+            }
+
+            #[inline]
+            fn as_mut_ptr(&mut self) -> *mut T {
+                <$ty>::as_mut_ptr(self)
+            }
+
+            #[inline]
+            unsafe fn set_len(&mut self, len: usize) {
+                <$ty>::set_len(self, len)
+            }
+
+            #[inline]
+            fn as_slice(&self) -> &[T] {
+                <$ty>::as_slice(self)
+            }
+
+            #[inline]
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                <$ty>::as_mut_slice(self)
+            }
+
+            #[inline]
+            fn swap_remove(&mut self, index: usize) -> T {
+                <$ty>::swap_remove(self, index)
+            }
+
+            #[inline]
+            fn insert(&mut self, index: usize, element: T) {
+                <$ty>::insert(self, index, element)
+            }
+
+            #[inline]
+            fn remove(&mut self, index: usize) -> T {
+                <$ty>::remove(self, index)
+            }
+
+            #[inline]
+            fn retain<F>(&mut self, f: F)
+            where
+                F: FnMut(&T) -> bool,
+            {
+                <$ty>::retain(self, f)
+            }
+
+            #[inline]
+            fn retain_mut<F>(&mut self, f: F)
+            where
+                F: FnMut(&mut T) -> bool,
+            {
+                <$ty>::retain_mut(self, f)
+            }
+
+            #[inline]
+            fn dedup(&mut self)
+            where
+                T: PartialEq,
+            {
+                <$ty>::dedup(self)
+            }
+
+            #[inline]
+            fn dedup_by_key<F, K>(&mut self, key: F)
+            where
+                F: FnMut(&mut T) -> K,
+                K: PartialEq,
+            {
+                <$ty>::dedup_by_key(self, key)
+            }
+
+            #[inline]
+            fn dedup_by<F>(&mut self, same_bucket: F)
+            where
+                F: FnMut(&mut T, &mut T) -> bool,
+            {
+                <$ty>::dedup_by(self, same_bucket)
+            }
+
+            #[inline]
+            fn push(&mut self, value: T) {
+                <$ty>::push(self, value)
+            }
+
+            #[inline]
+            fn clear(&mut self) {
+                <$ty>::clear(self)
+            }
+
+            #[inline]
+            fn len(&self) -> usize {
+                <$ty>::len(self)
+            }
+
+            #[inline]
+            fn is_empty(&self) -> bool {
+                <$ty>::is_empty(self)
+            }
+
+            #[inline]
+            fn pop(&mut self) -> Option<T> {
+                <$ty>::pop(self)
+            }
+
+            // =========================================================================
+            #[inline]
+            fn ensure_capacity(&mut self, new_len: usize) {
+                if new_len > self.capacity() {
+                    <$ty>::reserve(self, new_len - self.len())
+                }
+            }
+
+            #[inline]
+            fn try_ensure_capacity(&mut self, new_len: usize) -> Result<(), crate::CapacityError> {
+                if new_len > self.capacity() {
+                    <$ty>::try_reserve(self, new_len - self.len())
+                        .map_err(|_| crate::CapacityError::new(new_len, self.capacity()))?;
+                }
+                Ok(())
+            }
+
+            #[inline]
+            fn resize(&mut self, new_len: usize, value: T)
+            where
+                T: Clone,
+            {
+                <$ty>::resize(self, new_len, value)
+            }
+
+            #[inline]
+            fn resize_with<F>(&mut self, new_len: usize, f: F)
+            where
+                F: FnMut() -> T,
+            {
+                <$ty>::resize_with(self, new_len, f)
+            }
+
+            #[inline]
+            fn extend_from_within<R>(&mut self, src: R)
+            where
+                R: core::ops::RangeBounds<usize>,
+                T: Clone,
+            {
+                <$ty>::extend_from_within(self, src)
+            }
+
+            #[inline]
+            fn insert_slice(&mut self, index: usize, other: &[T])
+            where
+                T: Clone,
+            {
+                <$ty>::splice(self, index..index, other.iter().cloned());
+            }
+
+            #[inline]
+            fn insert_from_iter<I>(&mut self, index: usize, iter: I)
+            where
+                I: IntoIterator<Item = T>,
+            {
+                <$ty>::splice(self, index..index, iter);
+            }
         }
-    }
+    };
 }
+
+#[cfg(feature = "std")]
+impl_array_for_vec! { [] std::vec::Vec<T> }
+
+#[cfg(feature = "allocator-api2")]
+impl_array_for_vec! { [A: allocator_api2::alloc::Allocator] allocator_api2::vec::Vec<T, A> }
@@ -1,24 +1,56 @@
-use crate::*;
+use crate::{byte_io::write_within_capacity, *};
 use std::io;
 
+macro_rules! impl_io_write {
+    ($($(#[$meta:meta])* $ty:ident),*) => {$(
+        $(#[$meta])*
+        impl<const N: usize> io::Write for $ty<u8, N> {
+            #[inline]
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                Ok(write_within_capacity(self, buf))
+            }
 
-/// Write is implemented for `Vec<u8>` by appending to the vector.
-/// The vector will grow as needed.
-impl<const N: usize> io::Write for ArrayBuf<u8, N> {
-    #[inline]
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.extend_from_slice(buf);
-        Ok(buf.len())
-    }
+            #[inline]
+            fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+                if write_within_capacity(self, buf) == buf.len() {
+                    Ok(())
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ))
+                }
+            }
 
-    #[inline]
-    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
-        self.extend_from_slice(buf);
-        Ok(())
-    }
+            #[inline]
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+    )*};
+}
 
-    #[inline]
-    fn flush(&mut self) -> io::Result<()> {
-        Ok(())
-    }
-}
\ No newline at end of file
+impl_io_write! {
+    /// Write is implemented for `ArrayBuf<u8, N>` by appending to the array.
+    ///
+    /// The array never grows, Like `&mut [u8]`: A write larger than the remaining capacity
+    /// is a short write, And `write_all` fails with [`io::ErrorKind::WriteZero`] once the array is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_array::*;
+    /// use std::io::{ErrorKind, Write};
+    ///
+    /// let mut arr: ArrayBuf<u8, 4> = ArrayBuf::new();
+    /// assert_eq!(arr.write(b"Hello").unwrap(), 4);
+    /// assert_eq!(arr.write(b"World").unwrap(), 0);
+    ///
+    /// arr.clear();
+    /// let err = arr.write_all(b"Hello").unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::WriteZero);
+    /// ```
+    ArrayBuf,
+    /// Like `ArrayBuf<u8, N>`, Write appends to the array, Without growing it.
+    HeapArrayBuf
+}
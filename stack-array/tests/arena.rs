@@ -0,0 +1,72 @@
+#![cfg(feature = "allocator-api2")]
+
+use allocator_api2::{alloc::Allocator, vec::Vec};
+use core::alloc::Layout;
+use stack_array::{Array, StackArena};
+
+#[test]
+fn test_arena_alignment() {
+    let arena: StackArena<64> = StackArena::new();
+    let a = (&arena).allocate(Layout::new::<u8>()).unwrap();
+    let b = (&arena).allocate(Layout::new::<u64>()).unwrap();
+    let c = (&arena)
+        .allocate(Layout::from_size_align(4, 16).unwrap())
+        .unwrap();
+
+    assert_eq!(a.len(), 1);
+    assert_eq!(b.as_ptr() as *mut u8 as usize % 8, 0);
+    assert_eq!(c.as_ptr() as *mut u8 as usize % 16, 0);
+}
+
+#[test]
+fn test_arena_out_of_memory() {
+    let arena: StackArena<16> = StackArena::new();
+    assert!((&arena).allocate(Layout::new::<[u8; 17]>()).is_err());
+    assert_eq!(arena.used(), 0);
+
+    let mut list: Vec<u8, _> = Vec::new_in(&arena);
+    assert!(list.try_reserve(16).is_ok());
+    assert!(list.try_reserve(17).is_err());
+}
+
+#[test]
+fn test_arena_grow_in_place() {
+    let arena: StackArena<32> = StackArena::new();
+    let mut list = Vec::with_capacity_in(4, &arena);
+    list.extend_from_slice(b"abcd");
+    let ptr = list.as_ptr();
+
+    list.extend_from_slice(b"efgh");
+    assert_eq!(ptr, list.as_ptr());
+    assert_eq!(list, b"abcdefgh");
+    assert_eq!(arena.used(), list.capacity());
+
+    drop(list);
+    assert_eq!(arena.used(), 0);
+}
+
+#[test]
+fn test_arena_reset() {
+    let mut arena: StackArena<8> = StackArena::new();
+    drop(Vec::<u32, _>::with_capacity_in(1, &arena));
+    let _ = (&arena).allocate(Layout::new::<u32>()).unwrap();
+    assert_eq!(arena.used(), 4);
+
+    arena.reset();
+    assert_eq!(arena.used(), 0);
+    assert_eq!(arena.remaining(), 8);
+}
+
+#[test]
+fn test_arena_vec_array() {
+    fn fill(arr: &mut impl Array<u16>) {
+        for i in 0..4 {
+            arr.push(i);
+        }
+        arr.retain(|x| x % 2 == 0);
+    }
+    let arena: StackArena<64> = StackArena::new();
+    let mut list = Vec::new_in(&arena);
+    fill(&mut list);
+    assert_eq!(list, [0, 2]);
+}
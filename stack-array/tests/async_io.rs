@@ -0,0 +1,120 @@
+#![cfg(any(feature = "tokio", feature = "futures-io"))]
+
+use stack_array::{Array, ArrayBuf, HeapArrayBuf};
+use std::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+fn cx() -> Context<'static> {
+    Context::from_waker(Waker::noop())
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_tokio_write() {
+    use tokio::io::AsyncWrite;
+
+    let mut arr: ArrayBuf<u8, 8> = ArrayBuf::new();
+    let mut arr = Pin::new(&mut arr);
+    assert!(matches!(
+        arr.as_mut().poll_write(&mut cx(), b"Hello"),
+        Poll::Ready(Ok(5))
+    ));
+    assert!(matches!(
+        arr.as_mut().poll_write(&mut cx(), b"World"),
+        Poll::Ready(Ok(3))
+    ));
+    assert!(matches!(
+        arr.as_mut().poll_write(&mut cx(), b"!"),
+        Poll::Ready(Ok(0))
+    ));
+    assert_eq!(arr.as_slice(), b"HelloWor");
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_tokio_read() {
+    use std::io;
+    use tokio::io::{AsyncRead, ReadBuf};
+
+    let mut arr: ArrayBuf<u8, 8> = ArrayBuf::from(b"Hello".as_slice());
+    let mut out = [0; 3];
+    let mut buf = ReadBuf::new(&mut out);
+    assert!(Pin::new(&mut arr).poll_read(&mut cx(), &mut buf).is_ready());
+    assert_eq!(buf.filled(), b"Hel");
+    assert_eq!(arr.as_slice(), b"lo");
+
+    let mut cursor = io::Cursor::new(ArrayBuf::<u8, 8>::from(b"Hello".as_slice()));
+    let mut buf = ReadBuf::new(&mut out);
+    assert!(Pin::new(&mut cursor)
+        .poll_read(&mut cx(), &mut buf)
+        .is_ready());
+    assert_eq!(buf.filled(), b"Hel");
+    assert_eq!(cursor.position(), 3);
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn test_futures_io() {
+    use futures_io::{AsyncRead, AsyncWrite};
+
+    let mut arr: ArrayBuf<u8, 4> = ArrayBuf::new();
+    let mut arr = Pin::new(&mut arr);
+    assert!(matches!(
+        arr.as_mut().poll_write(&mut cx(), b"Hello"),
+        Poll::Ready(Ok(4))
+    ));
+    assert!(matches!(
+        arr.as_mut().poll_write(&mut cx(), b"!"),
+        Poll::Ready(Ok(0))
+    ));
+
+    let mut out = [0; 8];
+    assert!(matches!(
+        arr.as_mut().poll_read(&mut cx(), &mut out),
+        Poll::Ready(Ok(4))
+    ));
+    assert_eq!(&out[..4], b"Hell");
+    assert!(arr.is_empty());
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_tokio_heap() {
+    use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+    let mut arr: HeapArrayBuf<u8, 4> = HeapArrayBuf::new();
+    let mut arr = Pin::new(&mut arr);
+    assert!(matches!(
+        arr.as_mut().poll_write(&mut cx(), b"Hello"),
+        Poll::Ready(Ok(4))
+    ));
+
+    let mut out = [0; 3];
+    let mut buf = ReadBuf::new(&mut out);
+    assert!(arr.as_mut().poll_read(&mut cx(), &mut buf).is_ready());
+    assert_eq!(buf.filled(), b"Hel");
+    assert_eq!(arr.as_slice(), b"l");
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn test_futures_io_heap() {
+    use futures_io::{AsyncRead, AsyncWrite};
+
+    let mut arr: HeapArrayBuf<u8, 4> = HeapArrayBuf::new();
+    let mut arr = Pin::new(&mut arr);
+    assert!(matches!(
+        arr.as_mut().poll_write(&mut cx(), b"Hello"),
+        Poll::Ready(Ok(4))
+    ));
+
+    let mut out = [0; 8];
+    assert!(matches!(
+        arr.as_mut().poll_read(&mut cx(), &mut out),
+        Poll::Ready(Ok(4))
+    ));
+    assert_eq!(&out[..4], b"Hell");
+    assert!(arr.is_empty());
+}
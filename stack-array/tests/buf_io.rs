@@ -0,0 +1,104 @@
+#![cfg(feature = "std")]
+
+use stack_array::{StackBufReader, StackBufWriter};
+use std::io::{self, BufRead, Cursor, Read, Seek, SeekFrom, Write};
+
+#[test]
+fn test_buffered_reader() {
+    let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+    let mut reader: StackBufReader<_, 2> = StackBufReader::new(inner);
+
+    let mut buf = [0, 0, 0];
+    assert_eq!(reader.read(&mut buf).unwrap(), 3);
+    assert_eq!(buf, [5, 6, 7]);
+    assert_eq!(reader.buffer(), []);
+
+    let mut buf = [0, 0];
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(buf, [0, 1]);
+    assert_eq!(reader.buffer(), []);
+
+    let mut buf = [0];
+    assert_eq!(reader.read(&mut buf).unwrap(), 1);
+    assert_eq!(buf, [2]);
+    assert_eq!(reader.buffer(), [3]);
+
+    let mut buf = [0, 0, 0];
+    assert_eq!(reader.read(&mut buf).unwrap(), 1);
+    assert_eq!(buf, [3, 0, 0]);
+    assert_eq!(reader.buffer(), []);
+
+    assert_eq!(reader.fill_buf().unwrap(), [4]);
+    reader.consume(1);
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+}
+
+#[test]
+fn test_buffered_reader_seek() {
+    let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+    let mut reader: StackBufReader<_, 2> = StackBufReader::new(Cursor::new(inner));
+
+    assert_eq!(reader.seek(SeekFrom::Start(3)).unwrap(), 3);
+    assert_eq!(reader.fill_buf().unwrap(), [0, 1]);
+    assert_eq!(reader.stream_position().unwrap(), 3);
+    assert_eq!(reader.fill_buf().unwrap(), [0, 1]);
+    reader.consume(1);
+    assert_eq!(reader.seek(SeekFrom::Current(1)).unwrap(), 5);
+    assert_eq!(reader.fill_buf().unwrap(), [2, 3]);
+}
+
+#[test]
+fn test_buffered_writer() {
+    let mut writer: StackBufWriter<_, 2> = StackBufWriter::new(Vec::new());
+
+    writer.write_all(&[0, 1]).unwrap();
+    assert_eq!(writer.buffer(), []);
+    assert_eq!(*writer.get_ref(), [0, 1]);
+
+    writer.write_all(&[2]).unwrap();
+    assert_eq!(writer.buffer(), [2]);
+    assert_eq!(*writer.get_ref(), [0, 1]);
+
+    writer.write_all(&[3]).unwrap();
+    assert_eq!(writer.buffer(), [2, 3]);
+
+    writer.flush().unwrap();
+    assert_eq!(writer.buffer(), []);
+    assert_eq!(*writer.get_ref(), [0, 1, 2, 3]);
+
+    writer.write_all(&[4, 5, 6, 7]).unwrap();
+    assert_eq!(*writer.get_ref(), [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    writer.write_all(&[8]).unwrap();
+    assert_eq!(writer.into_inner().unwrap(), [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn test_buffered_writer_flush_on_drop() {
+    let mut vec = Vec::new();
+    {
+        let mut writer: StackBufWriter<_, 8> = StackBufWriter::new(&mut vec);
+        writer.write_all(b"abc").unwrap();
+    }
+    assert_eq!(vec, b"abc");
+}
+
+#[test]
+fn test_buffered_writer_into_inner_error() {
+    #[derive(Debug)]
+    struct Full;
+    impl Write for Full {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Ok(0)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+    let mut writer: StackBufWriter<_, 8> = StackBufWriter::new(Full);
+    writer.write_all(b"abc").unwrap();
+
+    let err = writer.into_inner().unwrap_err();
+    assert_eq!(err.error().kind(), io::ErrorKind::WriteZero);
+    assert_eq!(err.into_inner().buffer(), b"abc");
+}
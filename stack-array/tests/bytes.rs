@@ -0,0 +1,55 @@
+#![cfg(feature = "bytes")]
+
+use bytes::{Buf, BufMut};
+#[cfg(feature = "std")]
+use stack_array::HeapArrayBuf;
+use stack_array::{Array, ArrayBuf};
+
+#[test]
+fn test_buf_mut() {
+    let mut arr: ArrayBuf<u8, 6> = ArrayBuf::new();
+    assert_eq!(arr.remaining_mut(), 6);
+    assert_eq!(arr.chunk_mut().len(), 6);
+
+    arr.put_u32_le(7);
+    assert_eq!(arr.remaining_mut(), 2);
+    assert_eq!(arr.chunk_mut().len(), 2);
+
+    arr.put_bytes(0xff, 2);
+    assert!(!arr.has_remaining_mut());
+    assert_eq!(arr.as_slice(), [7, 0, 0, 0, 0xff, 0xff]);
+}
+
+#[test]
+#[should_panic]
+fn test_buf_mut_overflow() {
+    let mut arr: ArrayBuf<u8, 2> = ArrayBuf::new();
+    arr.put_u32(1);
+}
+
+#[test]
+fn test_buf_round_trip() {
+    let mut arr: ArrayBuf<u8, 16> = ArrayBuf::new();
+    arr.put_u64(u64::MAX - 1);
+    arr.put_i16(-2);
+
+    let mut reader = arr.reader();
+    assert_eq!(reader.remaining(), 10);
+    assert_eq!(reader.get_u64(), u64::MAX - 1);
+    assert_eq!(reader.get_i16(), -2);
+    assert_eq!(reader.position(), 10);
+    assert!(!reader.has_remaining());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_heap_buf_mut() {
+    let mut arr: HeapArrayBuf<u8, 6> = HeapArrayBuf::new();
+    arr.put_u32_le(7);
+    assert_eq!(arr.remaining_mut(), 2);
+    assert_eq!(arr.chunk_mut().len(), 2);
+
+    arr.put_bytes(0xff, 2);
+    assert!(!arr.has_remaining_mut());
+    assert_eq!(arr.as_slice(), [7, 0, 0, 0, 0xff, 0xff]);
+}
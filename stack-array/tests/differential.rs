@@ -0,0 +1,96 @@
+#![cfg(any(feature = "differential", feature = "arbitrary", feature = "proptest"))]
+
+use stack_array::differential::{check, Op};
+use stack_array::{Array, ArrayBuf};
+
+#[test]
+fn test_differential() {
+    let arr = check(
+        ArrayBuf::<u8, 6>::new(),
+        [
+            Op::Append(vec![1, 1, 2, 3, 3, 3]),
+            Op::Push(4),
+            Op::DedupBy(0),
+            Op::Insert(7, 5),
+            Op::Retain(0b1011),
+            Op::Remove(usize::MAX),
+            Op::Drain(1, 1),
+            Op::Truncate(1),
+        ],
+    );
+    assert_eq!(arr.as_slice(), [2]);
+}
+
+#[test]
+fn test_differential_vec() {
+    let vec = check(Vec::with_capacity(4), [Op::Push("a"), Op::Insert(0, "b")]);
+    assert_eq!(vec, ["b", "a"]);
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let data: Vec<u8> = (0..=255).collect();
+    let mut u = Unstructured::new(&data);
+    for _ in 0..8 {
+        let arr = ArrayBuf::<u8, 4>::arbitrary(&mut u).unwrap();
+        assert!(arr.len() <= 4);
+    }
+    let arr = ArrayBuf::<u16, 3>::arbitrary_take_rest(Unstructured::new(&[1; 64])).unwrap();
+    assert_eq!(arr.len(), 3);
+
+    let mut u = Unstructured::new(&data);
+    let ops: Vec<Op<u8>> = Arbitrary::arbitrary(&mut u).unwrap();
+    check(ArrayBuf::<u8, 8>::new(), ops);
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary_heap() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use stack_array::HeapArrayBuf;
+
+    let data: Vec<u8> = (0..=255).collect();
+    let mut u = Unstructured::new(&data);
+    for _ in 0..8 {
+        let arr = HeapArrayBuf::<u8, 4>::arbitrary(&mut u).unwrap();
+        assert!(arr.len() <= 4);
+    }
+    let arr = HeapArrayBuf::<u16, 3>::arbitrary_take_rest(Unstructured::new(&[1; 64])).unwrap();
+    assert_eq!(arr, [257; 3]);
+}
+
+#[cfg(feature = "proptest")]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+    use stack_array::strategy::{array_buf, heap_array_buf, op};
+
+    proptest! {
+        #[test]
+        fn array_buf_len(arr in array_buf::<_, 8>(any::<u8>(), 2..=8)) {
+            prop_assert!((2..=8).contains(&arr.len()));
+        }
+
+        #[test]
+        fn heap_array_buf_len(arr in heap_array_buf::<_, 8>(any::<u8>(), 2..=8)) {
+            prop_assert!((2..=8).contains(&arr.len()));
+        }
+
+        #[test]
+        fn differential(
+            arr in array_buf::<_, 16>(0..4_u8, 0..=16),
+            ops in prop::collection::vec(op(0..4_u8), 0..64),
+        ) {
+            check(arr, ops);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn array_buf_exceeds_capacity() {
+        let _ = array_buf::<_, 4>(any::<u8>(), 0..=5);
+    }
+}
@@ -0,0 +1,64 @@
+#![cfg(any(feature = "embedded-io", feature = "embedded-io-async"))]
+
+use stack_array::ArrayBuf;
+
+#[cfg(feature = "embedded-io")]
+#[test]
+fn test_write() {
+    use embedded_io::{Error, ErrorKind, Write};
+    use stack_array::Array;
+
+    let mut arr: ArrayBuf<u8, 8> = ArrayBuf::new();
+    assert_eq!(arr.write(b"Hello").unwrap(), 5);
+    assert_eq!(arr.write(b"World").unwrap(), 3);
+    assert_eq!(arr.write(b"").unwrap(), 0);
+    let err = arr.write(b"!").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfMemory);
+    assert_eq!(arr, *b"HelloWor");
+
+    arr.clear();
+    assert!(arr.write_all(b"Hello, World").is_err());
+    assert!(arr.is_full());
+    arr.flush().unwrap();
+}
+
+#[cfg(feature = "embedded-io")]
+#[test]
+fn test_read() {
+    use embedded_io::Read;
+
+    let mut arr: ArrayBuf<u8, 8> = ArrayBuf::from(*b"abcdef\0\0");
+    let mut buf = [0; 4];
+    assert_eq!(arr.read(&mut buf).unwrap(), 4);
+    assert_eq!(&buf, b"abcd");
+    arr.read_exact(&mut buf[..2]).unwrap();
+    assert_eq!(&buf[..2], b"ef");
+    assert_eq!(arr, *b"\0\0");
+}
+
+#[cfg(feature = "embedded-io-async")]
+#[test]
+fn test_async() {
+    use embedded_io_async::{Read, Write};
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    fn ready<F: Future>(fut: F) -> F::Output {
+        match pin!(fut).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("array I/O should never wait"),
+        }
+    }
+
+    let mut arr: ArrayBuf<u8, 4> = ArrayBuf::new();
+    assert_eq!(ready(arr.write(b"Hello")).unwrap(), 4);
+    assert!(ready(arr.write(b"!")).is_err());
+
+    let mut buf = [0; 8];
+    assert_eq!(ready(arr.read(&mut buf)).unwrap(), 4);
+    assert_eq!(&buf[..4], b"Hell");
+    assert_eq!(ready(arr.read(&mut buf)).unwrap(), 0);
+}
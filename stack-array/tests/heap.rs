@@ -0,0 +1,107 @@
+#![cfg(feature = "std")]
+
+#[cfg(any(feature = "differential", feature = "arbitrary", feature = "proptest"))]
+use stack_array::differential::{check, Op};
+use stack_array::{Array, ArrayBuf, HeapArrayBuf};
+use std::{collections::VecDeque, io::Write, rc::Rc, sync::Arc, thread};
+
+#[test]
+fn test_large_capacity() {
+    // 64 KiB stack, While the array is 8 MiB.
+    thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            let mut arr: HeapArrayBuf<u64, { 1024 * 1024 }> = HeapArrayBuf::new();
+            for i in 0..1024 {
+                arr.push(i);
+            }
+            assert_eq!(arr.len(), 1024);
+            assert_eq!(arr.remaining_capacity(), 1024 * 1024 - 1024);
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn test_conversion() {
+    let rc = Rc::new(());
+    let mut arr: ArrayBuf<Rc<()>, 4> = ArrayBuf::new();
+    arr.push(rc.clone());
+    arr.push(rc.clone());
+
+    let mut heap = HeapArrayBuf::from(arr);
+    assert_eq!(Rc::strong_count(&rc), 3);
+    heap.push(rc.clone());
+
+    let arr = ArrayBuf::from(heap);
+    assert_eq!(arr.len(), 3);
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    drop(arr);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_vec_conversion() {
+    let heap: HeapArrayBuf<_, 4> = HeapArrayBuf::try_from(vec![1, 2, 3]).unwrap();
+    assert_eq!(Vec::from(heap), [1, 2, 3]);
+    let err = HeapArrayBuf::<_, 2>::try_from(vec![1, 2, 3]).unwrap_err();
+    assert_eq!(err, [1, 2, 3]);
+
+    let heap = || HeapArrayBuf::<_, 4>::try_from_slice(&[1, 2]).unwrap();
+    assert_eq!(*Box::<[i32]>::from(heap()), [1, 2]);
+    assert_eq!(*Rc::<[i32]>::from(heap()), [1, 2]);
+    assert_eq!(*Arc::<[i32]>::from(heap()), [1, 2]);
+    assert_eq!(VecDeque::from(heap()), [1, 2]);
+    assert!(HeapArrayBuf::<_, 1>::try_from_slice(&[1, 2]).is_err());
+}
+
+#[test]
+fn test_split_off_concat() {
+    let mut heap = HeapArrayBuf::from([1, 2, 3, 4]);
+    let tail: HeapArrayBuf<_, 3> = heap.split_off(1);
+    assert_eq!(heap, [1]);
+    assert_eq!(tail, [2, 3, 4]);
+
+    let heap: HeapArrayBuf<_, 7> = tail.concat(heap);
+    assert_eq!(heap, [2, 3, 4, 1]);
+}
+
+#[test]
+#[should_panic]
+fn test_split_off_overflow() {
+    let mut heap = HeapArrayBuf::from([1, 2, 3, 4]);
+    let _: HeapArrayBuf<_, 2> = heap.split_off(1);
+}
+
+#[test]
+fn test_traits() {
+    let mut heap: HeapArrayBuf<u8, 4> = HeapArrayBuf::from([1, 2].as_slice());
+    let arr: ArrayBuf<u8, 4> = ArrayBuf::from([1, 2].as_slice());
+
+    assert_eq!(heap, arr);
+    assert_eq!(heap, [1, 2][..]);
+    assert_eq!(format!("{heap:?}"), "[1, 2]");
+
+    heap.write_all(&[3, 4]).unwrap();
+    assert!(heap.is_full());
+    assert!(heap.write_all(&[5]).is_err());
+    let smaller: HeapArrayBuf<u8, 4> = HeapArrayBuf::from([1, 2, 3, 3]);
+    assert!(heap > smaller);
+}
+
+#[cfg(any(feature = "differential", feature = "arbitrary", feature = "proptest"))]
+#[test]
+fn test_differential() {
+    check(
+        HeapArrayBuf::<u8, 4>::new(),
+        [
+            Op::Append(vec![1, 1, 2]),
+            Op::Insert(1, 3),
+            Op::DedupBy(0),
+            Op::Drain(1, 2),
+            Op::Retain(1),
+        ],
+    );
+}
@@ -0,0 +1,72 @@
+use stack_array::HistoryBuf;
+use std::rc::Rc;
+
+#[test]
+fn test_write_overwrites_oldest() {
+    let mut history: HistoryBuf<u8, 3> = HistoryBuf::new();
+    assert!(history.is_empty());
+    assert_eq!((history.recent(), history.oldest()), (None, None));
+
+    history.write(1);
+    history.write(2);
+    assert_eq!(history.as_slices(), (&[1, 2][..], &[][..]));
+    assert!(!history.is_full());
+
+    history.extend([3, 4, 5]);
+    assert!(history.is_full());
+    assert_eq!((history.len(), history.capacity()), (3, 3));
+    assert_eq!(history.as_slices(), (&[3][..], &[4, 5][..]));
+    assert_eq!((history.recent(), history.oldest()), (Some(&5), Some(&3)));
+    assert!(history.oldest_ordered().eq(&[3, 4, 5]));
+    assert_eq!(history.oldest_ordered().len(), 3);
+    assert_eq!(format!("{:?}", history.clone()), "[3, 4, 5]");
+
+    let (a, b) = history.as_mut_slices();
+    a[0] = 30;
+    b[1] = 50;
+    assert!(history.oldest_ordered().rev().eq(&[50, 4, 30]));
+
+    history.clear();
+    assert!(history.is_empty());
+    history.write(7);
+    assert_eq!(history.recent(), Some(&7));
+}
+
+#[test]
+fn test_zero_capacity() {
+    let rc = Rc::new(());
+    let mut history: HistoryBuf<Rc<()>, 0> = HistoryBuf::new();
+    history.write(rc.clone());
+    assert!(history.is_empty() && history.is_full());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_drop() {
+    let rc = Rc::new(());
+    let mut history: HistoryBuf<Rc<()>, 4> = HistoryBuf::new();
+    for _ in 0..10 {
+        history.write(rc.clone());
+    }
+    assert_eq!(Rc::strong_count(&rc), 5);
+    drop(history);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_stats() {
+    let mut history: HistoryBuf<f32, 4> = HistoryBuf::new();
+    assert_eq!(
+        (history.mean(), history.min(), history.max()),
+        (None, None, None)
+    );
+
+    history.extend_from_slice(&[100.0, 1.0, 2.0, 3.0, 6.0]);
+    assert_eq!(history.mean(), Some(3.0));
+    assert_eq!(history.min(), Some(1.0));
+    assert_eq!(history.max(), Some(6.0));
+
+    let mut ints: HistoryBuf<u16, 2> = HistoryBuf::new();
+    ints.extend(&[5, 7, 9]);
+    assert_eq!(ints.mean(), Some(8.0));
+}
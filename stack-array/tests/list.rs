@@ -0,0 +1,121 @@
+use stack_array::{ArrayList, CursorMut, ListIntoIter, ListIter, ListIterMut};
+use std::rc::Rc;
+
+#[test]
+fn test_front_back() {
+    let mut list: ArrayList<u8, 3> = ArrayList::new();
+    assert!(list.is_empty());
+    assert_eq!((list.front(), list.back()), (None, None));
+
+    list.push_back(2);
+    list.push_front(1);
+    list.push_back(3);
+    assert!(list.is_full());
+    assert_eq!((list.front(), list.back()), (Some(&1), Some(&3)));
+    *list.back_mut().unwrap() = 30;
+    assert!(list.iter().eq(&[1, 2, 30]));
+    assert!(list.iter().rev().eq(&[30, 2, 1]));
+
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_back(), Some(30));
+    // Freed slots are reused.
+    list.push_front(0);
+    list.push_back(4);
+    assert_eq!(format!("{list:?}"), "[0, 2, 4]");
+
+    for value in &mut list {
+        *value += 1;
+    }
+    assert_eq!(list, ArrayList::<u8, 5>::from_iter([1, 3, 5]));
+    assert!(list.clone().into_iter().rev().eq([5, 3, 1]));
+}
+
+#[test]
+#[should_panic(expected = "List is full")]
+fn test_push_full() {
+    let mut list = ArrayList::from([1, 2]);
+    list.push_back(3);
+}
+
+#[test]
+fn test_cursor() {
+    let mut list: ArrayList<u8, 8> = ArrayList::from_iter([1, 3, 5]);
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!(cursor.index(), Some(0));
+    cursor.insert_before(0);
+    assert_eq!(cursor.index(), Some(1));
+    cursor.insert_after(2);
+    assert_eq!(cursor.peek_next(), Some(&mut 2));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (Some(3), Some(&mut 3)));
+
+    assert_eq!(cursor.remove_current(), Some(3));
+    assert_eq!((cursor.index(), cursor.current()), (Some(3), Some(&mut 5)));
+    assert_eq!(cursor.remove_current(), Some(5));
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+    assert_eq!(cursor.remove_current(), None);
+
+    // The ghost sits between the back and the front.
+    assert_eq!(cursor.peek_next(), Some(&mut 0));
+    assert_eq!(cursor.peek_prev(), Some(&mut 2));
+    cursor.insert_after(9);
+    cursor.insert_before(7);
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (Some(4), Some(&mut 7)));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 9)));
+    cursor.move_prev();
+    assert_eq!(cursor.index(), None);
+    assert!(list.iter().eq(&[9, 0, 1, 2, 7]));
+
+    let mut cursor = list.cursor_back_mut();
+    assert_eq!(cursor.current(), Some(&mut 7));
+    cursor.move_prev();
+    cursor.splice_before(ArrayList::<_, 2>::from([10, 11]));
+    cursor.splice_after(ArrayList::<_, 1>::from([12]));
+    assert_eq!((cursor.index(), cursor.current()), (Some(5), Some(&mut 2)));
+    assert!(list.iter().eq(&[9, 0, 1, 10, 11, 2, 12, 7]));
+}
+
+#[test]
+fn test_splice_full() {
+    let mut list: ArrayList<u8, 3> = ArrayList::from_iter([1, 2]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        list.cursor_front_mut()
+            .splice_after(ArrayList::from([3, 4]));
+    }));
+    assert!(result.is_err());
+    assert!(list.iter().eq(&[1, 2]));
+}
+
+#[test]
+fn test_drop() {
+    let rc = Rc::new(());
+    let mut list: ArrayList<Rc<()>, 4> = ArrayList::new();
+    list.extend([rc.clone(), rc.clone(), rc.clone()]);
+    list.cursor_front_mut().remove_current();
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    let mut iter = list.clone().into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    drop(list);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_iterator_types() {
+    let mut list: ArrayList<u8, 3> = ArrayList::from([1, 2, 3]);
+    let iter: ListIter<'_, u8> = list.iter();
+    assert_eq!(iter.len(), 3);
+    let iter: ListIterMut<'_, u8> = list.iter_mut();
+    assert_eq!(iter.len(), 3);
+    let cursor: CursorMut<'_, u8, 3> = list.cursor_front_mut();
+    assert_eq!(cursor.index(), Some(0));
+    let iter: ListIntoIter<u8, 3> = list.into_iter();
+    assert!(iter.eq([1, 2, 3]));
+}
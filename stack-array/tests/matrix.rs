@@ -0,0 +1,104 @@
+use stack_array::ArrayMatrix;
+use std::rc::Rc;
+
+#[test]
+fn test_push_row() {
+    let mut grid: ArrayMatrix<u8, 3, 2> = ArrayMatrix::new();
+    assert!(grid.is_empty());
+    assert_eq!(grid.get(0, 0), None);
+
+    grid.push_row([1, 2]);
+    grid.push_row([3, 4]);
+    assert_eq!(
+        (grid.rows(), grid.columns(), grid.row_capacity()),
+        (2, 2, 3)
+    );
+    assert_eq!(grid.get(1, 1), Some(&4));
+    assert_eq!(grid.get(1, 2), None);
+    assert_eq!(grid.get(2, 0), None);
+    assert_eq!(grid.as_flat_slice(), [1, 2, 3, 4]);
+
+    grid[(0, 1)] = 5;
+    *grid.get_mut(1, 0).unwrap() = 6;
+    assert_eq!(grid.as_rows(), [[1, 5], [6, 4]]);
+
+    grid.push_row([7, 8]);
+    assert!(grid.is_full());
+    assert!(std::panic::catch_unwind(move || grid.push_row([0, 0])).is_err());
+}
+
+#[test]
+fn test_column() {
+    let mut grid: ArrayMatrix<usize, 3, 4> = ArrayMatrix::from_fn(|r, c| r * 10 + c);
+    assert!(grid.column(2).eq([&2, &12, &22]));
+    assert!(grid.column(3).rev().eq([&23, &13, &3]));
+    assert_eq!(grid.column(0).len(), 3);
+
+    for value in grid.column_mut(1) {
+        *value = 0;
+    }
+    assert_eq!(grid.row(2), Some(&[20, 0, 22, 23]));
+}
+
+#[test]
+#[should_panic]
+fn test_column_out_of_bounds() {
+    let grid: ArrayMatrix<u8, 2, 2> = ArrayMatrix::new();
+    grid.column(2);
+}
+
+#[test]
+fn test_transpose_and_map() {
+    let mut grid = ArrayMatrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    grid.transpose();
+    assert_eq!(grid.as_rows(), [[1, 4, 7], [2, 5, 8], [3, 6, 9]]);
+
+    let strings = grid.map(|v| v.to_string());
+    assert_eq!(strings[(2, 1)], "6");
+
+    let mut partial: ArrayMatrix<u8, 2, 2> = ArrayMatrix::new();
+    partial.push_row([1, 2]);
+    assert!(std::panic::catch_unwind(move || partial.transpose()).is_err());
+}
+
+#[test]
+fn test_drop() {
+    let rc = Rc::new(());
+    let mut grid: ArrayMatrix<Rc<()>, 4, 2> = ArrayMatrix::new();
+    grid.push_row([rc.clone(), rc.clone()]);
+    grid.push_row([rc.clone(), rc.clone()]);
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    drop(grid.pop_row());
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    let copy = grid.clone();
+    assert_eq!(copy, grid);
+    drop(copy);
+
+    let grid = grid.map(|v| v);
+    assert_eq!(Rc::strong_count(&rc), 3);
+    drop(grid);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_map_panic() {
+    let rc = Rc::new(());
+    let mut grid: ArrayMatrix<Rc<()>, 3, 2> = ArrayMatrix::new();
+    for _ in 0..3 {
+        grid.push_row([rc.clone(), rc.clone()]);
+    }
+    let mut calls = 0;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        grid.map(|v| {
+            calls += 1;
+            if calls == 4 {
+                panic!();
+            }
+            v
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "rayon")]
+
+use rayon::prelude::*;
+use stack_array::{Array, ArrayBuf, HeapArrayBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn test_par_iter() {
+    let mut arr: ArrayBuf<u32, 64> = ArrayBuf::new();
+    for i in 0..64 {
+        arr.push(i);
+    }
+    // `rend` (Pulled in by the `rkyv` feature) adds `PartialEq` impls for `u32`,
+    // So the type of the second sum can't be inferred with all features enabled.
+    assert_eq!(arr.par_iter().sum::<u32>(), (0..64).sum::<u32>());
+
+    arr.par_iter_mut().for_each(|x| *x *= 2);
+    assert_eq!(arr[63], 126);
+
+    let strings: Vec<String> = arr.into_par_iter().map(|x| x.to_string()).collect();
+    assert_eq!(strings[1], "2");
+}
+
+#[test]
+fn test_par_extend() {
+    let mut arr: ArrayBuf<u32, 8> = ArrayBuf::from([1, 2].as_slice());
+    arr.par_extend((3..=8).into_par_iter());
+    assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+#[should_panic]
+fn test_par_extend_overflow() {
+    let mut arr: ArrayBuf<u32, 4> = ArrayBuf::new();
+    arr.par_extend((0..5).into_par_iter());
+}
+
+#[test]
+fn test_par_extend_in_place() {
+    let mut arr: ArrayBuf<String, 1024> = ArrayBuf::new();
+    arr.push("start".to_string());
+    arr.par_extend((0..1000).into_par_iter().map(|i| i.to_string()));
+    assert_eq!(arr.len(), 1001);
+    assert!(arr[1..].iter().zip(0..).all(|(s, i)| *s == i.to_string()));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arr.par_extend((0..100).into_par_iter().map(|i| i.to_string()));
+    }));
+    assert!(result.is_err());
+    assert_eq!(arr.len(), 1001);
+}
+
+#[test]
+fn test_par_extend_unindexed() {
+    let mut arr: ArrayBuf<u32, 64> = ArrayBuf::new();
+    arr.par_extend((0..100).into_par_iter().filter(|i| i % 2 == 0));
+    assert!(arr.iter().copied().eq((0..100).step_by(2)));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arr.par_extend((0..100).into_par_iter().filter(|i| i % 5 == 0));
+    }));
+    assert!(result.is_err());
+    assert_eq!(arr.len(), 50);
+}
+
+#[test]
+fn test_par_extend_panic_drop_count() {
+    static MADE: AtomicUsize = AtomicUsize::new(0);
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct Elem;
+    impl Elem {
+        fn new() -> Self {
+            MADE.fetch_add(1, Ordering::Relaxed);
+            Elem
+        }
+    }
+    impl Drop for Elem {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut arr: ArrayBuf<Elem, 256> = ArrayBuf::new();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arr.par_extend((0..256).into_par_iter().map(|i| {
+            if i == 200 {
+                panic!("boom");
+            }
+            Elem::new()
+        }));
+    }));
+    assert!(result.is_err());
+    assert!(arr.is_empty());
+    // Every item that was written into the spare capacity is dropped, Exactly once.
+    assert_eq!(DROPS.load(Ordering::Relaxed), MADE.load(Ordering::Relaxed));
+}
+
+#[test]
+fn test_par_drain() {
+    let mut arr: ArrayBuf<String, 8> = ArrayBuf::new();
+    for i in 0..8 {
+        arr.push(i.to_string());
+    }
+    let drained: Vec<String> = arr.par_drain(2..6).collect();
+    assert_eq!(drained, ["2", "3", "4", "5"]);
+    assert_eq!(arr[..], ["0", "1", "6", "7"]);
+
+    // Not consumed, The range is still removed.
+    drop(arr.par_drain(1..3));
+    assert_eq!(arr[..], ["0", "7"]);
+}
+
+#[test]
+fn test_par_drain_drop_count() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct Elem;
+    impl Drop for Elem {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut arr: ArrayBuf<Elem, 16> = ArrayBuf::new();
+    for _ in 0..16 {
+        arr.push(Elem);
+    }
+    arr.par_drain(4..12).take(2).for_each(drop);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 8);
+    assert_eq!(arr.len(), 8);
+
+    drop(arr);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 16);
+}
+
+#[test]
+fn test_heap_par_iter() {
+    let mut arr: HeapArrayBuf<String, 4096> = HeapArrayBuf::new();
+    arr.par_extend((0..2000).into_par_iter().map(|i| i.to_string()));
+    arr.par_extend(
+        (2000..4000)
+            .into_par_iter()
+            .filter(|i| i % 2 == 0)
+            .map(|i| i.to_string()),
+    );
+    assert_eq!(arr.len(), 3000);
+    assert_eq!(arr[1999], "1999");
+    assert_eq!(arr[2000], "2000");
+    assert_eq!(arr[2001], "2002");
+
+    arr.par_iter_mut().for_each(|s| s.push('!'));
+    assert!(arr.par_iter().all(|s| s.ends_with('!')));
+
+    let drained: Vec<String> = arr.par_drain(..2).collect();
+    assert_eq!(drained, ["0!", "1!"]);
+    assert_eq!(arr.len(), 2998);
+
+    let strings: Vec<String> = arr.into_par_iter().collect();
+    assert_eq!(strings.len(), 2998);
+    assert_eq!(strings[0], "2!");
+}
+
+#[test]
+#[should_panic]
+fn test_heap_par_extend_overflow() {
+    let mut arr: HeapArrayBuf<u32, 4> = HeapArrayBuf::new();
+    arr.par_extend((0..10).into_par_iter().filter(|i| i % 2 == 0));
+}
+
+#[test]
+fn test_par_extend_unindexed_large() {
+    // 1 MiB chunks per split would overflow the 2 MiB worker stacks.
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+    let mut arr: Box<ArrayBuf<u64, { 1 << 17 }>> = ArrayBuf::new_boxed();
+    pool.install(|| arr.par_extend((0..1 << 18).into_par_iter().filter(|i| i % 2 == 0)));
+    assert_eq!(arr.len(), 1 << 17);
+    assert!(arr.iter().copied().eq((0..1 << 18).step_by(2)));
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "rkyv")]
+
+use rkyv::{rancor::Error, util::AlignedVec, Archived};
+use stack_array::{ArchivedArrayBuf, Array, ArrayBuf};
+
+type Archive = ArchivedArrayBuf<Archived<String>, 4>;
+
+#[test]
+fn test_rkyv_round_trip() {
+    let mut arr: ArrayBuf<String, 4> = ArrayBuf::new();
+    arr.push("Hello".into());
+    arr.push("World".into());
+
+    let bytes = rkyv::to_bytes::<Error>(&arr).unwrap();
+    let archived = rkyv::access::<Archive, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 2);
+    assert_eq!(archived.capacity(), 4);
+    assert_eq!(archived[0], "Hello");
+
+    let value: ArrayBuf<String, 4> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(value, arr);
+}
+
+#[test]
+fn test_rkyv_fixed_size() {
+    let empty: ArrayBuf<u64, 8> = ArrayBuf::new();
+    let full = ArrayBuf::from([1_u64; 8]);
+    let a = rkyv::to_bytes::<Error>(&empty).unwrap();
+    let b = rkyv::to_bytes::<Error>(&full).unwrap();
+    assert_eq!(a.len(), b.len());
+}
+
+#[test]
+fn test_rkyv_reject_invalid_len() {
+    let arr: ArrayBuf<u32, 2> = ArrayBuf::from([1, 2]);
+    let bytes = rkyv::to_bytes::<Error>(&arr).unwrap();
+
+    let mut invalid = AlignedVec::<16>::new();
+    invalid.extend_from_slice(&bytes);
+    // The root object is at the end of the buffer, and `len` is its first field.
+    let len = bytes.len() - size_of::<ArchivedArrayBuf<Archived<u32>, 2>>();
+    invalid[len] = 3;
+
+    let result = rkyv::access::<ArchivedArrayBuf<Archived<u32>, 2>, Error>(&invalid);
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_rkyv_heap_round_trip() {
+    use stack_array::HeapArrayBuf;
+
+    let mut heap: HeapArrayBuf<String, 4> = HeapArrayBuf::new();
+    heap.push("Hello".into());
+
+    let bytes = rkyv::to_bytes::<Error>(&heap).unwrap();
+    let archived = rkyv::access::<Archive, Error>(&bytes).unwrap();
+    assert_eq!(archived, &heap);
+
+    // The archive is the same, Whether the slots were on the stack or the heap.
+    let arr: ArrayBuf<String, 4> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(bytes[..], rkyv::to_bytes::<Error>(&arr).unwrap()[..]);
+    let value: HeapArrayBuf<String, 4> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(value, heap);
+}
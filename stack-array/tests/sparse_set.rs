@@ -0,0 +1,57 @@
+use stack_array::SparseSet;
+use std::rc::Rc;
+
+#[test]
+fn test_insert_remove() {
+    let mut set: SparseSet<char, 8> = SparseSet::new();
+    assert!(set.is_empty());
+    assert_eq!(set.capacity(), 8);
+    assert!(!set.contains(0) && !set.contains(100));
+
+    assert_eq!(set.insert(5, 'a'), None);
+    assert_eq!(set.insert(0, 'b'), None);
+    assert_eq!(set.insert(7, 'c'), None);
+    assert_eq!(set.insert(0, 'B'), Some('b'));
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.ids(), [5, 0, 7]);
+    assert_eq!(set.values(), ['a', 'B', 'c']);
+
+    assert_eq!(set.remove(5), Some('a'));
+    assert_eq!(set.remove(5), None);
+    assert_eq!(set.ids(), [7, 0]);
+    assert_eq!(
+        (set.get(7), set.get(0), set.get(5)),
+        (Some(&'c'), Some(&'B'), None)
+    );
+
+    *set.get_mut(7).unwrap() = 'C';
+    for (_, value) in set.iter_mut() {
+        value.make_ascii_lowercase();
+    }
+    assert!(set.iter().eq([(7, &'c'), (0, &'b')]));
+    assert_eq!(format!("{:?}", set.clone()), "{7: 'c', 0: 'b'}");
+
+    // A stale sparse entry must not be mistaken for a member.
+    set.clear();
+    assert!(!set.contains(7) && !set.contains(0));
+    set.insert(3, 'd');
+    assert!(!set.contains(7));
+}
+
+#[test]
+#[should_panic(expected = "id (is 4) should be < capacity (is 4)")]
+fn test_insert_out_of_range() {
+    let mut set: SparseSet<u8, 4> = SparseSet::new();
+    set.insert(4, 0);
+}
+
+#[test]
+fn test_drop() {
+    let rc = Rc::new(());
+    let mut set: SparseSet<Rc<()>, 16> = (0..10).map(|id| (id, rc.clone())).collect();
+    set.remove(3);
+    set.insert(4, rc.clone());
+    assert_eq!(Rc::strong_count(&rc), 10);
+    drop(set);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
@@ -163,6 +163,52 @@ fn test_retain() {
     assert_eq!(arr.as_ref(), [2, 4]);
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn test_retain_variants() {
+    use std::{panic, rc::Rc};
+
+    let mut arr: ArrayBuf<u8, 6> = ArrayBuf::from([1, 2, 3, 4, 5, 6]);
+    arr.retain_mut(|x| {
+        *x *= 10;
+        *x != 30
+    });
+    assert_eq!(arr[..], [10, 20, 40, 50, 60]);
+
+    let mut indices = vec![];
+    arr.retain_with_index(|i, _| {
+        indices.push(i);
+        i != 1
+    });
+    assert_eq!(indices, [0, 1, 2, 3, 4]);
+    assert_eq!(arr[..], [10, 40, 50, 60]);
+
+    let mut visited = 0;
+    arr.retain_while(|x| {
+        visited += 1;
+        (*x < 50).then_some(*x != 40)
+    });
+    assert_eq!(visited, 3);
+    assert_eq!(arr[..], [10, 50, 60]);
+
+    let mut vec = vec![1, 2, 3];
+    Array::retain_mut(&mut vec, |x| *x != 2);
+    Array::retain_while(&mut vec, |_| None);
+    assert_eq!(vec, [1, 3]);
+
+    // The rest are shifted back, Even if the predicate panics.
+    let rc = Rc::new(());
+    let mut arr: ArrayBuf<Rc<()>, 4> = ArrayBuf::new();
+    arr.fill_to_capacity(|| rc.clone());
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        arr.retain_with_index(|i, _| if i == 2 { panic!() } else { i != 0 })
+    }));
+    assert!(result.is_err());
+    assert_eq!(arr.len(), 3);
+    drop(arr);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
 type Arr = ArrayBuf<u8, 10>;
 macro_rules! arr {
     () => (
@@ -189,3 +235,362 @@ fn test_dedup_by_key() {
     case(arr![10, 20, 30, 31], arr![10, 20, 30]);
     case(arr![10, 11, 20, 21, 22, 30, 31], arr![10, 20, 30]);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io_write_bounded() {
+    use std::io::{ErrorKind, Write};
+
+    let mut v: ArrayBuf<u8, 4> = ArrayBuf::new();
+    assert_eq!(v.write(b"ab").unwrap(), 2);
+    assert_eq!(v.write(b"cde").unwrap(), 2);
+    assert_eq!(v.write(b"f").unwrap(), 0);
+    assert_eq!(v.write_all(b"g").unwrap_err().kind(), ErrorKind::WriteZero);
+    assert_eq!(v.as_slice(), b"abcd");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_in_place_construction() {
+    use std::{mem::MaybeUninit, thread};
+
+    // 64 KiB stack, While the arrays are 8 MiB.
+    thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            let arr: Box<ArrayBuf<u64, { 1024 * 1024 }>> = ArrayBuf::new_boxed();
+            assert!(arr.is_empty());
+
+            let arr: Box<ArrayBuf<u64, { 1024 * 1024 }>> = ArrayBuf::new_boxed_with(|arr| {
+                arr.extend_from_slice([1, 2, 3]);
+            });
+            assert_eq!(arr.as_slice(), [1, 2, 3]);
+
+            let mut slot: Box<MaybeUninit<ArrayBuf<u64, { 1024 * 1024 }>>> = Box::new_uninit();
+            let arr = ArrayBuf::new_in(&mut slot);
+            arr.push(4);
+            assert_eq!(arr.as_slice(), [4]);
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    let arr: ArrayBuf<u8, 8> = ArrayBuf::new_checked::<{ size_of::<ArrayBuf<u8, 8>>() }>();
+    assert!(arr.is_empty());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck() {
+    use bytemuck::Zeroable;
+
+    let arr: ArrayBuf<u32, 4> = ArrayBuf::zeroed();
+    assert!(arr.is_empty());
+
+    let arr: ArrayBuf<u32, 4> = ArrayBuf::from([1, 2].as_slice());
+    assert_eq!(arr.as_bytes().len(), 8);
+
+    let words: [usize; 3] = [2, 7, 8];
+    let bytes = bytemuck::bytes_of(&words);
+    assert!(ArrayBuf::<usize, 2>::try_from_bytes(bytes).is_ok());
+    assert!(ArrayBuf::<usize, 1>::try_from_bytes(bytes).is_err());
+    assert!(ArrayBuf::<usize, 2>::try_from_bytes(&bytes[1..]).is_err());
+}
+
+#[test]
+fn test_split_off_concat() {
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let mut arr: ArrayBuf<Rc<()>, 4> = ArrayBuf::new();
+    for _ in 0..4 {
+        arr.push(rc.clone());
+    }
+
+    let tail: ArrayBuf<_, 3> = arr.split_off(1);
+    assert_eq!((arr.len(), tail.len()), (1, 3));
+    assert!(std::panic::catch_unwind(|| {
+        let mut arr = ArrayBuf::from([1, 2, 3]);
+        let _: ArrayBuf<u8, 1> = arr.split_off(1);
+    })
+    .is_err());
+
+    let arr: ArrayBuf<_, 8> = arr.concat(tail);
+    assert_eq!(arr.len(), 4);
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    let arr = arr.resize_capacity::<3>().unwrap_err();
+    let arr = arr.resize_capacity::<4>().unwrap();
+    assert_eq!(arr.capacity(), 4);
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    drop(arr);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_resize() {
+    let mut arr: ArrayBuf<String, 4> = ArrayBuf::new();
+    arr.resize(2, "a".into());
+    arr.resize_with(3, || "b".into());
+    assert_eq!(arr[..], ["a", "a", "b"]);
+
+    let err = arr.try_resize(5, "c".into()).unwrap_err();
+    assert_eq!((err.required(), err.capacity()), (5, 4));
+    assert!(arr.try_resize_with(5, String::new).is_err());
+    assert_eq!(arr.len(), 3);
+
+    arr.resize(1, String::new());
+    assert_eq!(arr[..], ["a"]);
+
+    arr.fill_to_capacity(|| "z".into());
+    assert_eq!(arr[..], ["a", "z", "z", "z"]);
+
+    let mut vec = Vec::with_capacity(3);
+    Array::resize(&mut vec, 2, 7);
+    Array::fill_to_capacity(&mut vec, || 8);
+    assert_eq!(vec[..2], [7, 7]);
+    assert_eq!(vec.len(), vec.capacity());
+}
+
+#[test]
+fn test_resize_clone_panic() {
+    use std::{cell::Cell, panic};
+
+    thread_local!(static CLONES: Cell<usize> = const { Cell::new(0) });
+
+    #[derive(Debug)]
+    struct Bomb;
+    impl Clone for Bomb {
+        fn clone(&self) -> Self {
+            let clones = CLONES.with(|c| c.replace(c.get() + 1));
+            if clones == 2 {
+                panic!("boom");
+            }
+            Bomb
+        }
+    }
+
+    let mut arr: ArrayBuf<Bomb, 8> = ArrayBuf::new();
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| arr.resize(5, Bomb)));
+    assert!(result.is_err());
+    assert_eq!(arr.len(), 2);
+}
+
+#[test]
+fn test_extend_from_within() {
+    let mut arr: ArrayBuf<String, 6> = ArrayBuf::new();
+    arr.push("a".into());
+    arr.push("b".into());
+    arr.extend_from_within(..);
+    arr.extend_from_within(1..2);
+    assert_eq!(arr[..], ["a", "b", "a", "b", "b"]);
+
+    assert!(arr.try_extend_from_within(..2).is_err());
+    assert_eq!(arr.len(), 5);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_insert_slice() {
+    let mut arr: ArrayBuf<u8, 6> = ArrayBuf::from([1, 6].as_slice());
+    arr.insert_slice(1, &[2, 3]);
+    arr.insert_from_iter(3, [4, 5]);
+    assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+
+    let mut arr: ArrayBuf<String, 3> = ArrayBuf::new();
+    arr.push("a".into());
+    assert!(arr.try_insert_slice(0, &["b".into(), "c".into(), "d".into()]).is_err());
+    let err = arr.try_insert_from_iter(1, (0..5).map(|i| i.to_string())).unwrap_err();
+    assert_eq!(err.required(), 6);
+    assert_eq!(arr[..], ["a"]);
+
+    let mut count = 0;
+    let unbounded = std::iter::repeat_with(|| {
+        count += 1;
+        "b".to_string()
+    });
+    let err = arr.try_insert_from_iter(0, unbounded).unwrap_err();
+    assert!(err.required() > err.capacity());
+    assert_eq!(count, 3);
+    assert_eq!(arr[..], ["a"]);
+
+    let mut vec = vec![1, 4];
+    Array::insert_slice(&mut vec, 1, &[2, 3]);
+    Array::insert_from_iter(&mut vec, 4, [5]);
+    assert_eq!(vec, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic = "insertion index (is 2) should be <= len (is 1)"]
+fn test_insert_slice_out_of_bounds() {
+    let mut arr: ArrayBuf<u8, 4> = ArrayBuf::from([1].as_slice());
+    arr.insert_slice(2, &[2]);
+}
+
+#[test]
+fn test_insert_slice_panic() {
+    use std::{panic, rc::Rc};
+
+    /// Panics on clone, If the value is `0`.
+    #[derive(Debug, PartialEq)]
+    struct Bomb(u8, Rc<()>);
+    impl Clone for Bomb {
+        fn clone(&self) -> Self {
+            if self.0 == 0 {
+                panic!("boom");
+            }
+            Bomb(self.0, self.1.clone())
+        }
+    }
+
+    let rc = Rc::new(());
+    let bomb = |i| Bomb(i, rc.clone());
+    let mut arr: ArrayBuf<Bomb, 8> = ArrayBuf::new();
+    arr.push(bomb(1));
+    arr.push(bomb(2));
+
+    let other = [bomb(3), bomb(4), bomb(0)];
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| arr.insert_slice(1, &other)));
+    assert!(result.is_err());
+    assert_eq!(arr[..], [bomb(1), bomb(2)]);
+    drop(other);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    let iter = (3..6).map(|i| if i == 5 { panic!("boom") } else { bomb(i) });
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| arr.insert_from_iter(0, iter)));
+    assert!(result.is_err());
+    assert_eq!(arr[..], [bomb(1), bomb(2)]);
+    assert_eq!(Rc::strong_count(&rc), 3);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sorted() {
+    let mut arr: ArrayBuf<u8, 8> = ArrayBuf::new();
+    for value in [5, 1, 4, 1, 3] {
+        arr.insert_sorted(value);
+    }
+    assert_eq!(arr[..], [1, 1, 3, 4, 5]);
+    assert!(arr.contains_sorted(&4));
+    assert_eq!(arr.remove_sorted(&4), Some(4));
+    assert_eq!(arr.remove_sorted(&4), None);
+    assert!(!arr.contains_sorted(&4));
+
+    let mut pairs: ArrayBuf<(u8, char), 4> = ArrayBuf::new();
+    assert_eq!(pairs.insert_sorted_by_key((2, 'a'), |p| p.0), 0);
+    assert_eq!(pairs.insert_sorted_by_key((1, 'b'), |p| p.0), 0);
+    assert_eq!(pairs.insert_sorted_by_key((2, 'c'), |p| p.0), 2);
+    assert_eq!(pairs[..], [(1, 'b'), (2, 'a'), (2, 'c')]);
+
+    let mut arr = ArrayBuf::from([1, 1, 2, 3, 3]);
+    arr.dedup_sorted_all();
+    assert_eq!(arr[..], [2]);
+
+    let mut vec = vec![1, 3];
+    Array::insert_sorted(&mut vec, 2);
+    assert_eq!(vec, [1, 2, 3]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_merge_sorted() {
+    use std::rc::Rc;
+
+    let mut arr: ArrayBuf<(u8, char), 8> =
+        ArrayBuf::from([(1, 'a'), (3, 'a'), (5, 'a')].as_slice());
+    let mut other = ArrayBuf::from([(0, 'b'), (3, 'a'), (6, 'b')]);
+    arr.merge_sorted(&mut other);
+    assert_eq!(
+        arr[..],
+        [(0, 'b'), (1, 'a'), (3, 'a'), (3, 'a'), (5, 'a'), (6, 'b')]
+    );
+    assert!(other.is_empty());
+
+    let rc = Rc::new(());
+    let mut arr: ArrayBuf<Rc<()>, 4> = ArrayBuf::new();
+    arr.push(rc.clone());
+    let mut vec = vec![rc.clone(), rc.clone()];
+    arr.merge_sorted(&mut vec);
+    assert_eq!((arr.len(), vec.len()), (3, 0));
+    drop(arr);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let mut vec = vec![2, 4];
+    Array::merge_sorted(&mut vec, &mut ArrayBuf::from([1, 3, 5]));
+    assert_eq!(vec, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_merge_sorted_panic() {
+    use std::{cmp::Ordering, panic, rc::Rc};
+
+    #[derive(PartialEq, Eq)]
+    struct Bomb(Rc<()>, u8);
+    impl PartialOrd for Bomb {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Bomb {
+        fn cmp(&self, other: &Self) -> Ordering {
+            if self.1 == 9 || other.1 == 9 {
+                panic!("boom");
+            }
+            self.1.cmp(&other.1)
+        }
+    }
+
+    let rc = Rc::new(());
+    let mut arr: ArrayBuf<Bomb, 6> = ArrayBuf::new();
+    arr.push(Bomb(rc.clone(), 1));
+    arr.push(Bomb(rc.clone(), 9));
+    let mut other: ArrayBuf<Bomb, 3> = ArrayBuf::new();
+    other.push(Bomb(rc.clone(), 2));
+    other.push(Bomb(rc.clone(), 3));
+
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| arr.merge_sorted(&mut other)));
+    assert!(result.is_err());
+    assert_eq!((arr.len(), other.len()), (4, 0));
+    drop(arr);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_cross_capacity_cmp() {
+    let a: ArrayBuf<u8, 3> = ArrayBuf::from([1, 2, 3]);
+    let b: ArrayBuf<u8, 8> = ArrayBuf::from(&[1, 2, 3][..]);
+    assert_eq!(a, b);
+    assert_eq!(a, vec![1, 2, 3]);
+    assert_eq!(vec![1, 2, 3], b);
+    assert_eq!([1, 2, 3], b);
+    assert_ne!(b, [1, 2]);
+
+    let c: ArrayBuf<u8, 5> = ArrayBuf::from(&[1, 2, 4][..]);
+    assert!(a < c && c > b);
+    assert!(b < vec![1, 2, 3, 0]);
+    assert!([1, 3] > b);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_conversions() {
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let new = || ArrayBuf::<String, 4>::try_from(vec!["a".to_string(), "b".to_string()]).unwrap();
+    let vec = Vec::from(new());
+    assert_eq!((vec.len(), vec.capacity()), (2, 2));
+    assert_eq!(vec, new());
+    assert_eq!(*Box::<[String]>::from(new()), ["a", "b"]);
+    assert_eq!(*Rc::<[String]>::from(new()), ["a", "b"]);
+    assert_eq!(*Arc::<[String]>::from(new()), ["a", "b"]);
+    assert_eq!(VecDeque::from(new()), ["a", "b"]);
+
+    let err = ArrayBuf::<u8, 2>::try_from(vec![1, 2, 3]).unwrap_err();
+    assert_eq!(err, [1, 2, 3]);
+    assert!(ArrayBuf::<String, 1>::try_from_slice(&["a".to_string(), "b".to_string()]).is_err());
+}
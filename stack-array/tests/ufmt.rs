@@ -0,0 +1,29 @@
+#![cfg(feature = "ufmt")]
+
+use stack_array::{Array, ArrayBuf};
+use ufmt::uWrite;
+
+#[test]
+fn test_write_str() {
+    let mut buf: ArrayBuf<u8, 6> = ArrayBuf::new();
+    buf.write_str("abc").unwrap();
+    buf.write_char('d').unwrap();
+
+    let err = buf.write_str("efg").unwrap_err();
+    assert_eq!((err.required(), err.capacity()), (7, 6));
+    assert_eq!(buf, *b"abcd");
+
+    buf.write_str("ef").unwrap();
+    assert!(buf.is_full());
+}
+
+#[test]
+fn test_debug() {
+    let mut arr: ArrayBuf<ArrayBuf<u8, 2>, 3> = ArrayBuf::new();
+    arr.push(ArrayBuf::from([1, 2]));
+    arr.push(ArrayBuf::new());
+
+    let mut out: ArrayBuf<u8, 32> = ArrayBuf::new();
+    ufmt::uwrite!(out, "{:?}", arr).unwrap();
+    assert_eq!(out, *format!("{arr:?}").as_bytes());
+}
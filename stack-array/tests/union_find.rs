@@ -0,0 +1,76 @@
+use stack_array::UnionFind;
+
+#[test]
+fn test_union_find() {
+    let mut uf: UnionFind<8> = UnionFind::new();
+    assert_eq!((uf.len(), uf.count_sets()), (8, 8));
+    assert!(!uf.connected(0, 1));
+
+    assert!(uf.union(0, 1));
+    assert!(uf.union(2, 3));
+    assert!(uf.union(1, 3));
+    assert!(!uf.union(0, 2));
+    assert!(uf.union(7, 5));
+
+    assert!(uf.connected(0, 3));
+    assert!(!uf.connected(0, 5));
+    assert_eq!((uf.set_size(2), uf.set_size(5), uf.set_size(4)), (4, 2, 1));
+    assert_eq!(uf.count_sets(), 4);
+
+    let groups: Vec<_> = uf.components().collect();
+    assert_eq!(groups.len(), 4);
+    assert_eq!(groups[0], [0, 1, 2, 3]);
+    assert_eq!(groups[1], [4]);
+    assert_eq!(groups[2], [5, 7]);
+    assert_eq!(groups[3], [6]);
+    assert_eq!(uf.components().len(), 4);
+
+    uf.reset();
+    assert_eq!(uf.count_sets(), 8);
+    assert_eq!(uf, UnionFind::default());
+}
+
+#[test]
+fn test_partition_eq() {
+    let mut a: UnionFind<6> = UnionFind::new();
+    a.union(0, 1);
+    a.union(1, 2);
+    a.union(4, 5);
+
+    // Same sets, But different roots and ranks.
+    let mut b: UnionFind<6> = UnionFind::new();
+    b.union(5, 4);
+    b.union(2, 1);
+    b.union(2, 0);
+    b.find(0);
+    assert_eq!(a, b);
+
+    b.union(3, 4);
+    assert_ne!(a, b);
+    a.union(5, 3);
+    assert_eq!(a, b);
+    a.union(0, 3);
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_long_chain() {
+    let mut uf: UnionFind<100> = UnionFind::new();
+    for i in 1..100 {
+        uf.union(i - 1, i);
+    }
+    assert_eq!(uf.count_sets(), 1);
+    assert_eq!(uf.set_size(42), 100);
+    let root = uf.find(0);
+    assert!((0..100).all(|i| uf.find(i) == root));
+    let mut components = uf.components();
+    assert!(components.next().unwrap().iter().copied().eq(0..100));
+    assert_eq!(components.next(), None);
+}
+
+#[test]
+fn test_empty() {
+    let mut uf: UnionFind<0> = UnionFind::new();
+    assert!(uf.is_empty());
+    assert_eq!(uf.components().next(), None);
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "zeroize")]
+
+#[cfg(feature = "std")]
+use stack_array::HeapArrayBuf;
+use stack_array::{Array, ArrayBuf, SecretArrayBuf};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+#[test]
+fn test_array_buf_zeroize() {
+    let mut arr: ArrayBuf<u8, 4> = ArrayBuf::from([1, 2, 3].as_slice());
+    arr.zeroize();
+    assert!(arr.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_heap_array_buf_zeroize() {
+    let mut arr: HeapArrayBuf<u8, 4> = HeapArrayBuf::from([1, 2, 3].as_slice());
+    arr.zeroize();
+    assert!(arr.is_empty());
+}
+
+#[test]
+fn test_zeroize_on_drop() {
+    // `ArrayBuf<u8, 32>` isn't, Because `u8` doesn't zeroize itself on drop.
+    assert_zeroize_on_drop::<SecretArrayBuf<u8, 32>>();
+    assert_zeroize_on_drop::<ArrayBuf<SecretArrayBuf<u8, 32>, 2>>();
+}
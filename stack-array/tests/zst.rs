@@ -0,0 +1,162 @@
+#[cfg(any(feature = "differential", feature = "arbitrary", feature = "proptest"))]
+use stack_array::differential::{check, Op};
+#[cfg(feature = "std")]
+use stack_array::HeapArrayBuf;
+use stack_array::{Array, ArrayBuf};
+use std::cell::Cell;
+
+thread_local! {
+    static DROPS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A zero-sized type, That counts its drops.
+#[derive(Debug, PartialEq)]
+struct Zst;
+
+impl Drop for Zst {
+    fn drop(&mut self) {
+        DROPS.with(|drops| drops.set(drops.get() + 1));
+    }
+}
+
+fn drops() -> usize {
+    DROPS.with(|drops| drops.take())
+}
+
+fn filled<const N: usize>() -> ArrayBuf<Zst, N> {
+    let mut arr = ArrayBuf::new();
+    while !arr.is_full() {
+        arr.push(Zst);
+    }
+    arr
+}
+
+#[test]
+fn test_zst_capacity() {
+    let mut arr: ArrayBuf<(), 3> = ArrayBuf::new();
+    assert_eq!(arr.capacity(), 3);
+    arr.extend_from_slice([(), (), ()]);
+    assert!(arr.is_full());
+    assert_eq!(arr.remaining_capacity(), 0);
+    assert!(std::panic::catch_unwind(move || arr.push(())).is_err());
+
+    #[cfg(feature = "std")]
+    {
+        let arr: HeapArrayBuf<(), 3> = HeapArrayBuf::new();
+        assert_eq!(arr.capacity(), 3);
+    }
+}
+
+#[test]
+fn test_zst_drop_count() {
+    drops();
+    let mut arr = filled::<8>();
+    assert_eq!(drops(), 0);
+
+    drop(arr.pop());
+    drop(arr.remove(0));
+    drop(arr.swap_remove(0));
+    assert_eq!((arr.len(), drops()), (5, 3));
+
+    arr.insert(2, Zst);
+    arr.truncate(4);
+    assert_eq!((arr.len(), drops()), (4, 2));
+
+    let mut n = 0;
+    arr.retain(|_| {
+        n += 1;
+        n % 2 == 0
+    });
+    assert_eq!((arr.len(), drops()), (2, 2));
+
+    arr.dedup();
+    assert_eq!((arr.len(), drops()), (1, 1));
+
+    let mut other: ArrayBuf<Zst, 8> = filled::<8>();
+    other.truncate(3);
+    drops();
+    arr.append(&mut other);
+    assert_eq!((arr.len(), other.len(), drops()), (4, 0, 0));
+
+    arr.clear();
+    assert_eq!((arr.len(), drops()), (0, 4));
+}
+
+#[test]
+fn test_zst_drain() {
+    drops();
+    let mut arr = filled::<8>();
+
+    let mut drain = arr.drain(2..6);
+    assert_eq!(drain.len(), 4);
+    drop(drain.next());
+    drop(drain.next_back());
+    assert_eq!(drops(), 2);
+    drop(drain);
+    assert_eq!((arr.len(), drops()), (4, 2));
+
+    assert_eq!(arr.drain(..).count(), 4);
+    assert_eq!((arr.len(), drops()), (0, 4));
+
+    let arr = filled::<8>();
+    drop(arr);
+    assert_eq!(drops(), 8);
+
+    #[cfg(feature = "std")]
+    {
+        let heap = HeapArrayBuf::from(filled::<4>());
+        drop(ArrayBuf::from(heap));
+        assert_eq!(drops(), 4);
+    }
+}
+
+#[test]
+fn test_zero_capacity() {
+    let mut arr: ArrayBuf<String, 0> = ArrayBuf::new();
+    assert_eq!(arr.capacity(), 0);
+    assert!(arr.is_empty() && arr.is_full());
+    assert_eq!(arr.pop(), None);
+    assert_eq!(arr.drain(..).count(), 0);
+    arr.truncate(0);
+    arr.retain(|_| true);
+    arr.dedup();
+    #[cfg(feature = "std")]
+    arr.append(&mut Vec::new());
+    assert!(arr.as_slice().is_empty());
+    assert!(std::panic::catch_unwind(move || arr.push(String::new())).is_err());
+
+    let mut arr: ArrayBuf<String, 0> = ArrayBuf::new();
+    assert!(std::panic::catch_unwind(move || arr.insert(0, String::new())).is_err());
+
+    #[cfg(feature = "std")]
+    {
+        let mut arr: HeapArrayBuf<String, 0> = HeapArrayBuf::new();
+        assert!(arr.is_full());
+        assert_eq!(arr.drain(..).count(), 0);
+    }
+
+    let arr: ArrayBuf<Zst, 0> = filled();
+    assert!(arr.is_empty());
+}
+
+#[cfg(any(feature = "differential", feature = "arbitrary", feature = "proptest"))]
+#[test]
+fn test_differential() {
+    let ops = [
+        Op::Append(vec![(), (), ()]),
+        Op::Push(()),
+        Op::Insert(1, ()),
+        Op::Remove(2),
+        Op::Drain(0, 2),
+        Op::Retain(0),
+        Op::Push(()),
+        Op::DedupBy(0),
+        Op::Truncate(5),
+    ];
+    check(ArrayBuf::<(), 4>::new(), ops.clone());
+    check(ArrayBuf::<(), 0>::new(), ops.clone());
+    check(
+        ArrayBuf::<u8, 0>::new(),
+        [Op::Push(1), Op::Append(vec![1]), Op::Drain(1, 1)],
+    );
+}